@@ -104,6 +104,32 @@ extern "C" {
 extern "C" {
     pub fn c_ctre_phoenix6_is_simulation() -> ::std::os::raw::c_int;
 }
+extern "C" {
+    #[doc = " \\brief Feed the robot enable.\n This function does nothing on a roborio during FRC use.\n\n \\param timeoutMs Timeout before disabling"]
+    pub fn c_ctre_phoenix6_unmanaged_feed_enable(timeoutMs: ::std::os::raw::c_int);
+}
+extern "C" {
+    #[doc = " \\returns true if enabled"]
+    pub fn c_ctre_phoenix6_unmanaged_get_enable_state() -> bool;
+}
+extern "C" {
+    #[doc = " \\brief Sets whether to enable transmitting\n This function does nothing on a roborio during FRC use.\n\n \\param en True enables transmitting"]
+    pub fn c_ctre_phoenix6_unmanaged_set_transmit_enable(en: bool);
+}
+extern "C" {
+    #[doc = " \\returns true if transmitting is enabled"]
+    pub fn c_ctre_phoenix6_unmanaged_get_transmit_enable() -> bool;
+}
+extern "C" {
+    #[doc = " \\returns Phoenix version"]
+    pub fn c_ctre_phoenix6_unmanaged_get_phoenix_version() -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn c_ctre_phoenix6_unmanaged_load_phoenix();
+}
+extern "C" {
+    pub fn c_ctre_phoenix6_unmanaged_get_api_compliancy() -> ::std::os::raw::c_int;
+}
 extern "C" {
     pub fn c_ctre_phoenix6_platform_canbus_sendmessage(
         messageId: u32,
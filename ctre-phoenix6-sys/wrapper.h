@@ -4,7 +4,7 @@
 #include "ctre/phoenix/cci/Diagnostics_CCI.h"
 #include "ctre/phoenix/Context.h"
 #include "ctre/phoenix6/Utils.h"
-// #include "ctre/phoenix6/unmanaged/unmanaged_c_style.h"
+#include "ctre/phoenix6/unmanaged/unmanaged_c_style.h"
 // #include "ctre/phoenix6/spns/SpnValue.h"
 #include "ctre/phoenix6/platform/platform_c_style.h"
 #include "ctre/phoenix6/networking/interfaces/Configs_Interface.h"
@@ -0,0 +1,44 @@
+//! A small pool of threads that runs the blocking phoenix calls behind the async api,
+//! this keeps the crate independent of any particular executor.
+
+use flume::Sender;
+use once_cell::sync::Lazy;
+
+use crate::{error::StatusCode, Status};
+
+type Job = Box<dyn FnOnce() + Send>;
+
+const WORKER_COUNT: usize = 2;
+
+static POOL: Lazy<Sender<Job>> = Lazy::new(|| {
+    let (sender, receiver) = flume::unbounded::<Job>();
+    for i in 0..WORKER_COUNT {
+        let receiver = receiver.clone();
+        // if no worker could be spawned sending a job fails instead of hanging
+        let _ = std::thread::Builder::new()
+            .name(format!("phoenix6-blocking-{i}"))
+            .spawn(move || {
+                while let Ok(job) = receiver.recv() {
+                    job();
+                }
+            });
+    }
+    sender
+});
+
+/// Runs `job` on the blocking pool and waits for it without blocking the executor
+pub(crate) async fn run<T, F>(job: F) -> Status<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> Status<T> + Send + 'static,
+{
+    let (sender, receiver) = flume::bounded(1);
+    POOL.send(Box::new(move || {
+        let _ = sender.send(job());
+    }))
+    .map_err(|_| StatusCode::GeneralError)?;
+    receiver
+        .recv_async()
+        .await
+        .map_err(|_| StatusCode::GeneralError)?
+}
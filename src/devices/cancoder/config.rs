@@ -14,6 +14,8 @@ use crate::{
 pub trait CanCoderConfigType: ConfigProtocol {}
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct CanCoderConfig {
     /// True if we should factory default newer unsupported configs,
     /// false to leave newer unsupported configs alone.
@@ -75,6 +77,7 @@ impl CanCoderConfigType for CanCoderConfig {}
 #[derive(
     Debug, Clone, Copy, PartialEq, Eq, Default, num_enum::IntoPrimitive, num_enum::TryFromPrimitive,
 )]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(i32)]
 pub enum SensorDirectionValue {
     #[default]
@@ -93,6 +96,7 @@ impl std::fmt::Display for SensorDirectionValue {
 #[derive(
     Debug, Clone, Copy, PartialEq, Eq, Default, num_enum::IntoPrimitive, num_enum::TryFromPrimitive,
 )]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(i32)]
 pub enum AbsoluteSensorRangeValue {
     #[default]
@@ -109,6 +113,8 @@ impl std::fmt::Display for AbsoluteSensorRangeValue {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct MagnetSensorConfigs {
     /// Direction of the sensor to determine positive facing the
     /// LED side of the CANcoder.
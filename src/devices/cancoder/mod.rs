@@ -1,14 +1,27 @@
 pub mod config;
 pub mod signals;
 
+use frclib_core::units::{
+    angle::Rotation,
+    time::{Second, Time},
+};
 use parking_lot::RwLock;
 use std::sync::Arc;
 
-use crate::Status;
+use crate::{signals::faults::Fault, spn::SPN, Status};
 
-use self::{config::CanCoderConfigType, signals::CanCoderCache};
+use self::{
+    config::{CanCoderConfig, CanCoderConfigType},
+    signals::CanCoderCache,
+};
 
-use super::{close_device, config_native, propose_device, DeviceCanID, DeviceIdentifier};
+use super::{
+    close_device,
+    config_dump::ConfigDump,
+    config_native,
+    licensing::{self, LicenseStatus},
+    propose_device, version, DeviceCanID, DeviceIdentifier, ParentDevice,
+};
 
 //do not implement clone for this
 #[derive(Debug)]
@@ -33,6 +46,72 @@ impl CanCoder {
             identifier: &mut self.identifier,
         }
     }
+
+    /// Sets the position of the device, the write is confirmed by the device
+    /// before this returns.
+    ///
+    /// Reading the cached `position` signal fails with
+    /// [`StatusCode::SigNotUpdated`](crate::error::StatusCode::SigNotUpdated) until it is refreshed.
+    pub fn set_position(&self, position: Rotation) -> Status<()> {
+        self.set_position_timeout(position, Second::new(crate::DEFAULT_TIMEOUT))
+    }
+
+    /// Sets the position of the device, the write is confirmed by the device
+    /// before this returns.
+    pub fn set_position_timeout(&self, position: Rotation, timeout: impl Time) -> Status<()> {
+        config_native::send_action(
+            &self.identifier,
+            SPN::CANCODER_SET_SENSOR_POSITION,
+            position.value(),
+            timeout.to_seconds().value(),
+        )?;
+        //the cached position is stale now
+        self.cache.write().invalidate_position();
+        Ok(())
+    }
+
+    /// Checks that the firmware on the device is compatible with the phoenix library
+    /// this crate is running against, see [`ParentDevice::check_compatibility`].
+    ///
+    /// A mismatch is reported as [`StatusCode::FirmwareTooOld`](crate::error::StatusCode::FirmwareTooOld),
+    /// [`StatusCode::FirmwareTooNew`](crate::error::StatusCode::FirmwareTooNew)
+    /// or [`StatusCode::ApiTooOld`](crate::error::StatusCode::ApiTooOld).
+    pub fn check_compatibility_timeout(&self, timeout: impl Time) -> Status<()> {
+        version::check_compatibility(&self.identifier, timeout.to_seconds().value())
+    }
+
+    /// Reads the Pro and Season Pass licensing of the device
+    /// and whether it is using a feature it is not licensed for
+    pub fn license_status(&self) -> Status<LicenseStatus> {
+        licensing::license_status(&self.identifier, crate::DEFAULT_TIMEOUT)
+    }
+
+    /// Reads the Pro and Season Pass licensing of the device
+    /// and whether it is using a feature it is not licensed for
+    pub fn license_status_timeout(&self, timeout: impl Time) -> Status<LicenseStatus> {
+        licensing::license_status(&self.identifier, timeout.to_seconds().value())
+    }
+
+    /// Clears all sticky faults on the device, see [`ParentDevice::clear_sticky_faults`]
+    pub fn clear_sticky_faults_timeout(&self, timeout: impl Time) -> Status<()> {
+        config_native::clear_sticky_faults(&self.identifier, timeout.to_seconds().value())
+    }
+
+    /// Clears a single sticky fault on the device.
+    ///
+    /// Returns [`StatusCode::InvalidModel`](crate::error::StatusCode::InvalidModel)
+    /// if this device can not report `fault`.
+    pub fn clear_sticky_fault(&self, fault: Fault) -> Status<()> {
+        config_native::clear_sticky_fault(&self.identifier, fault, crate::DEFAULT_TIMEOUT)
+    }
+
+    /// Clears a single sticky fault on the device.
+    ///
+    /// Returns [`StatusCode::InvalidModel`](crate::error::StatusCode::InvalidModel)
+    /// if this device can not report `fault`.
+    pub fn clear_sticky_fault_timeout(&self, fault: Fault, timeout: impl Time) -> Status<()> {
+        config_native::clear_sticky_fault(&self.identifier, fault, timeout.to_seconds().value())
+    }
 }
 
 impl Drop for CanCoder {
@@ -41,6 +120,12 @@ impl Drop for CanCoder {
     }
 }
 
+impl ParentDevice for CanCoder {
+    fn identifier(&self) -> &DeviceIdentifier {
+        &self.identifier
+    }
+}
+
 pub struct CanCoderConfigurator<'hw> {
     //mutable so it holds a unique reference to the device
     identifier: &'hw mut DeviceIdentifier,
@@ -59,15 +144,78 @@ impl CanCoderConfigurator<'_> {
     pub fn apply_config_timeout(
         &mut self,
         config: impl CanCoderConfigType,
-        timeout: f64,
+        timeout: impl Time,
     ) -> Status<()> {
         let fpc = config.future_proof_configs();
-        config_native::set_config(self.identifier.clone(), config, timeout, fpc, true)
+        config_native::set_config(
+            self.identifier.clone(),
+            config,
+            timeout.to_seconds().value(),
+            fpc,
+            true,
+        )
+    }
+    /// Applies the config without blocking the async executor
+    #[cfg(feature = "async")]
+    pub async fn apply_config_async(
+        &mut self,
+        config: impl CanCoderConfigType + Send + 'static,
+    ) -> Status<()> {
+        self.apply_config_timeout_async(
+            config,
+            frclib_core::units::time::Second::new(crate::DEFAULT_TIMEOUT),
+        )
+        .await
+    }
+    /// Applies the config without blocking the async executor
+    #[cfg(feature = "async")]
+    pub async fn apply_config_timeout_async(
+        &mut self,
+        config: impl CanCoderConfigType + Send + 'static,
+        timeout: impl Time,
+    ) -> Status<()> {
+        let fpc = config.future_proof_configs();
+        let identifier = self.identifier.clone();
+        let timeout = timeout.to_seconds().value();
+        crate::blocking::run(move || {
+            config_native::set_config(identifier, config, timeout, fpc, true)
+        })
+        .await
     }
     pub fn get_config<T: CanCoderConfigType>(&self) -> Status<T> {
         config_native::get_config(self.identifier.clone(), crate::DEFAULT_TIMEOUT)
     }
-    pub fn get_config_timeout<T: CanCoderConfigType>(&self, timeout: f64) -> Status<T> {
-        config_native::get_config(self.identifier.clone(), timeout)
+    pub fn get_config_timeout<T: CanCoderConfigType>(&self, timeout: impl Time) -> Status<T> {
+        config_native::get_config(self.identifier.clone(), timeout.to_seconds().value())
+    }
+    /// Resets every config on the device to its factory default,
+    /// including configs this version of the crate does not know about.
+    pub fn factory_default(&mut self) -> Status<()> {
+        config_native::set_config(
+            self.identifier.clone(),
+            CanCoderConfig::default(),
+            crate::DEFAULT_TIMEOUT,
+            true,
+            true,
+        )
+    }
+    /// Resets every config on the device to its factory default,
+    /// including configs this version of the crate does not know about.
+    pub fn factory_default_timeout(&mut self, timeout: impl Time) -> Status<()> {
+        config_native::set_config(
+            self.identifier.clone(),
+            CanCoderConfig::default(),
+            timeout.to_seconds().value(),
+            true,
+            true,
+        )
+    }
+    /// Reads back every config value the device reports
+    pub fn refresh_all(&self) -> Status<ConfigDump> {
+        config_native::get_config(self.identifier.clone(), crate::DEFAULT_TIMEOUT)
+    }
+    /// Reads back every config value the device reports
+    pub fn refresh_all_timeout(&self, timeout: impl Time) -> Status<ConfigDump> {
+        config_native::get_config(self.identifier.clone(), timeout.to_seconds().value())
     }
 }
@@ -5,12 +5,14 @@ use parking_lot::RwLock;
 
 use crate::{
     cold_signal,
-    devices::DeviceIdentifier,
+    devices::{version::FirmwareVersion, DeviceIdentifier},
     error::StatusCode,
     signal, signal_setup,
     signals::{
-        native, types::MagnetHealthValue, BaseSignal, RefreshableStatusSignal, SPNValue,
-        SignalValue, SignalValueRaw,
+        faults::{FaultSet, StickyFaultSet},
+        native,
+        types::MagnetHealthValue,
+        BaseSignal, CachedValue, RefreshableStatusSignal, SPNValue, SignalValue, SignalValueRaw,
     },
     spn::SPN,
     Status,
@@ -20,12 +22,18 @@ use super::CanCoder;
 
 #[derive(Debug, Default)]
 pub(super) struct CanCoderCache {
-    velocity: SignalValueRaw,
-    raw_velocity: SignalValueRaw,
-    position: SignalValueRaw,
-    abs_position: SignalValueRaw,
-    raw_position: SignalValueRaw,
-    supply_voltage: SignalValueRaw,
+    velocity: CachedValue,
+    raw_velocity: CachedValue,
+    position: CachedValue,
+    abs_position: CachedValue,
+    raw_position: CachedValue,
+    supply_voltage: CachedValue,
+}
+impl CanCoderCache {
+    pub(super) fn invalidate_position(&mut self) {
+        // reads fail until a refresh returns a sample from after the write
+        self.position.invalidate();
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -39,6 +47,11 @@ pub enum CanCoderSignalField {
     SupplyVoltage = SPN::CANCODER_SUPPLY_VOLTAGE as i32,
     MagnetHealth = SPN::CANCODER_MAG_HEALTH as i32,
     IsPro = SPN::LICENSING_IS_PRO_LICENSED as i32,
+    IsSeasonPassed = SPN::LICENSING_IS_SEASON_PASSED as i32,
+    AllFaults = SPN::ALL_FAULTS as i32,
+    AllStickyFaults = SPN::ALL_STICKY_FAULTS as i32,
+    VersionFull = SPN::VERSION_FULL as i32,
+    CompliancyVersion = SPN::COMPLIANCY_VERSION as i32,
     //(sticky)faults
     StickyFaultHardware = SPN::STICKY_FAULT_HARDWARE as i32,
     FaultHardware = SPN::FAULT_HARDWARE as i32,
@@ -94,7 +107,7 @@ impl<T: SPNValue> BaseSignal<T> for CanCoderSignal<T> {
                 CanCoderSignalField::SupplyVoltage => cache.supply_voltage,
                 _ => unreachable!("This should not happen, this is a cold signal."),
             };
-            Ok(SignalValue::<T>::from(value))
+            value.read()
         } else {
             let ret = native::request_signal_value_single(
                 native::SignalMeta {
@@ -133,7 +146,7 @@ impl<T: SPNValue> BaseSignal<T> for CanCoderSignal<T> {
     }
 }
 
-impl<T: SPNValue> RefreshableStatusSignal<T> for CanCoderSignal<T> {
+impl<T: SPNValue> RefreshableStatusSignal for CanCoderSignal<T> {
     fn refresh(&self) -> Status<()> {
         if self.cache.is_none() {
             return Ok(());
@@ -147,24 +160,34 @@ impl<T: SPNValue> RefreshableStatusSignal<T> for CanCoderSignal<T> {
                 hash: self.identifier.hash.0,
                 spn: self.get_spn(),
             },
-        )?;
-        let cache = self
-            .cache
-            .as_ref()
-            .expect("Cache was None, this should not happen.")
-            .upgrade()
-            .ok_or(StatusCode::InvalidDeviceDescriptor)?;
+        );
+        self.update_cache(ret)
+    }
+
+    fn device_identifier(&self) -> &DeviceIdentifier {
+        &self.identifier
+    }
+
+    fn spn(&self) -> SPN {
+        self.get_spn()
+    }
+
+    fn update_cache(&self, value: Status<SignalValueRaw>) -> Status<()> {
+        let Some(cache) = &self.cache else {
+            return value.map(|_| ());
+        };
+        let cache = cache.upgrade().ok_or(StatusCode::InvalidDeviceDescriptor)?;
         let mut cache = cache.write();
-        match self.field {
-            CanCoderSignalField::Velocity => cache.velocity = ret,
-            CanCoderSignalField::RawVelocity => cache.raw_velocity = ret,
-            CanCoderSignalField::Position => cache.position = ret,
-            CanCoderSignalField::AbsolutePosition => cache.abs_position = ret,
-            CanCoderSignalField::RawPosition => cache.raw_position = ret,
-            CanCoderSignalField::SupplyVoltage => cache.supply_voltage = ret,
+        let entry = match self.field {
+            CanCoderSignalField::Velocity => &mut cache.velocity,
+            CanCoderSignalField::RawVelocity => &mut cache.raw_velocity,
+            CanCoderSignalField::Position => &mut cache.position,
+            CanCoderSignalField::AbsolutePosition => &mut cache.abs_position,
+            CanCoderSignalField::RawPosition => &mut cache.raw_position,
+            CanCoderSignalField::SupplyVoltage => &mut cache.supply_voltage,
             _ => unreachable!("This should not happen, this is a cold signal."),
         };
-        Ok(())
+        entry.update(value)
     }
 }
 
@@ -183,6 +206,11 @@ signal! {supply_voltage -> SupplyVoltage<Volt>}
 
 cold_signal! {magnet_health -> MagnetHealth<MagnetHealthValue>}
 cold_signal! {is_pro -> IsPro<bool>}
+cold_signal! {is_season_passed -> IsSeasonPassed<bool>}
+cold_signal! {faults -> AllFaults<FaultSet>}
+cold_signal! {sticky_faults -> AllStickyFaults<StickyFaultSet>}
+cold_signal! {version -> VersionFull<FirmwareVersion>}
+cold_signal! {compliancy_version -> CompliancyVersion<i32>}
 
 cold_signal! (sticky_fault_hardware -> StickyFaultHardware<bool>);
 cold_signal! (fault_hardware -> FaultHardware<bool>);
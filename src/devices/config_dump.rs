@@ -0,0 +1,307 @@
+use crate::{seal, spn::SPN, Status};
+
+use super::{
+    config_native::{deserialize_bool, deserialize_double, deserialize_int},
+    ConfigProtocol,
+};
+
+/// How the device encodes the value of a config SPN
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ConfigKind {
+    Double,
+    Int,
+    Bool,
+}
+impl ConfigKind {
+    /// The kind of every SPN a config struct of this crate reads,
+    /// other SPNs have their kind inferred from the text of the value
+    fn of(spn: SPN) -> Option<Self> {
+        match spn {
+            SPN::CANCODER_MAGNET_OFFSET
+            | SPN::PIGEON2_MOUNT_POSE_YAW
+            | SPN::PIGEON2_MOUNT_POSE_PITCH
+            | SPN::PIGEON2_MOUNT_POSE_ROLL
+            | SPN::PIGEON2_GYRO_SCALARX
+            | SPN::PIGEON2_GYRO_SCALARY
+            | SPN::PIGEON2_GYRO_SCALARZ
+            | SPN::CONFIG_DUTY_CYCLE_NEUTRAL_DB
+            | SPN::CONFIG_PEAK_FORWARD_DC
+            | SPN::CONFIG_PEAK_REVERSE_DC
+            | SPN::CONFIG_STATOR_CURRENT_LIMIT
+            | SPN::CONFIG_SUPPLY_CURRENT_LIMIT
+            | SPN::CONFIG_SUPPLY_CURR_THRES
+            | SPN::CONFIG_SUPPLY_TIME_THRES
+            | SPN::CONFIG_SUPPLY_VLOWPASS_TAU
+            | SPN::CONFIG_PEAK_FORWARDV
+            | SPN::CONFIG_PEAK_REVERSEV
+            | SPN::CONFIG_PEAK_FOR_TORQ_CURR
+            | SPN::CONFIG_PEAK_REV_TORQ_CURR
+            | SPN::CONFIG_TORQUE_NEUTRAL_DB
+            | SPN::CONFIG_FEEDBACK_ROTOR_OFFSET
+            | SPN::CONFIG_SENSOR_TO_MECHANISM_RATIO
+            | SPN::CONFIG_ROTOR_TO_SENSOR_RATIO
+            | SPN::CONFIG_PEAK_DIFF_DC
+            | SPN::CONFIG_PEAK_DIFFV
+            | SPN::CONFIG_PEAK_DIFF_TORQ_CURR
+            | SPN::CONFIG_DUTY_CYCLE_OPEN_LOOP_RAMP_PERIOD
+            | SPN::CONFIG_VOLTAGE_OPEN_LOOP_RAMP_PERIOD
+            | SPN::CONFIG_TORQUE_OPEN_LOOP_RAMP_PERIOD
+            | SPN::CONFIG_DUTY_CYCLE_CLOSED_LOOP_RAMP_PERIOD
+            | SPN::CONFIG_VOLTAGE_CLOSED_LOOP_RAMP_PERIOD
+            | SPN::CONFIG_TORQUE_CLOSED_LOOP_RAMP_PERIOD
+            | SPN::CONFIG_FORWARD_LIMIT_AUTOSET_POS_VALUE
+            | SPN::CONFIG_REVERSE_LIMIT_AUTOSET_POS_VALUE
+            | SPN::CONFIG_FORWARD_SOFT_LIMIT_THRESHOLD
+            | SPN::CONFIG_REVERSE_SOFT_LIMIT_THRESHOLD
+            | SPN::CONFIG_MOTION_MAGIC_CRUISE_VELOCITY
+            | SPN::CONFIG_MOTION_MAGIC_ACCELERATION
+            | SPN::CONFIG_MOTION_MAGIC_JERK
+            | SPN::CONFIG_MOTION_MAGIC_EXPO_KV
+            | SPN::CONFIG_MOTION_MAGIC_EXPO_KA
+            | SPN::SLOT0_KP
+            | SPN::SLOT0_KI
+            | SPN::SLOT0_KD
+            | SPN::SLOT0_KS
+            | SPN::SLOT0_KV
+            | SPN::SLOT0_KA
+            | SPN::SLOT0_KG
+            | SPN::SLOT1_KP
+            | SPN::SLOT1_KI
+            | SPN::SLOT1_KD
+            | SPN::SLOT1_KS
+            | SPN::SLOT1_KV
+            | SPN::SLOT1_KA
+            | SPN::SLOT1_KG
+            | SPN::SLOT2_KP
+            | SPN::SLOT2_KI
+            | SPN::SLOT2_KD
+            | SPN::SLOT2_KS
+            | SPN::SLOT2_KV
+            | SPN::SLOT2_KA
+            | SPN::SLOT2_KG => Some(ConfigKind::Double),
+            SPN::CANCODER_SENSOR_DIRECTION
+            | SPN::CANCODER_ABSOLUTE_SENSOR_RANGE
+            | SPN::CONFIG_INVERTED
+            | SPN::CONFIG_NEUTRAL_MODE
+            | SPN::CONFIG_FEEDBACK_SENSOR_SOURCE
+            | SPN::CONFIG_FEEDBACK_REMOTE_SENSOR_ID
+            | SPN::CONFIG_DIFFERENTIAL_SENSOR_SOURCE
+            | SPN::CONFIG_DIFFERENTIAL_TALON_FXSENSOR_ID
+            | SPN::CONFIG_DIFFERENTIAL_REMOTE_SENSOR_ID
+            | SPN::CONFIG_FORWARD_LIMIT_TYPE
+            | SPN::CONFIG_FORWARD_LIMIT_SOURCE
+            | SPN::CONFIG_FORWARD_LIMIT_REMOTE_SENSOR_ID
+            | SPN::CONFIG_REVERSE_LIMIT_TYPE
+            | SPN::CONFIG_REVERSE_LIMIT_SOURCE
+            | SPN::CONFIG_REVERSE_LIMIT_REMOTE_SENSOR_ID
+            | SPN::CUSTOM_PARAM0
+            | SPN::CUSTOM_PARAM1
+            | SPN::SLOT0_KG_TYPE
+            | SPN::SLOT1_KG_TYPE
+            | SPN::SLOT2_KG_TYPE => Some(ConfigKind::Int),
+            SPN::PIGEON2_USE_COMPASS
+            | SPN::PIGEON2_DISABLE_TEMPERATURE_COMPENSATION
+            | SPN::PIGEON2_DISABLE_NO_MOTION_CALIBRATION
+            | SPN::CONFIG_STATOR_CURR_LIMIT_EN
+            | SPN::CONFIG_SUPPLY_CURR_LIMIT_EN
+            | SPN::CONFIG_FORWARD_LIMIT_AUTOSET_POS_ENABLE
+            | SPN::CONFIG_FORWARD_LIMIT_ENABLE
+            | SPN::CONFIG_REVERSE_LIMIT_AUTOSET_POS_ENABLE
+            | SPN::CONFIG_REVERSE_LIMIT_ENABLE
+            | SPN::CONFIG_BEEP_ON_BOOT
+            | SPN::CONFIG_BEEP_ON_CONFIG
+            | SPN::CONFIG_ALLOW_MUSIC_DUR_DISABLE
+            | SPN::CONFIG_FORWARD_SOFT_LIMIT_ENABLE
+            | SPN::CONFIG_REVERSE_SOFT_LIMIT_ENABLE
+            | SPN::CONFIG_CONTINUOUS_WRAP => Some(ConfigKind::Bool),
+            _ => None,
+        }
+    }
+}
+
+/// A single value read back from a device config
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ConfigValue {
+    Double(f64),
+    Int(i32),
+    Bool(bool),
+}
+impl ConfigValue {
+    /// Reads the value of a known SPN out of the config string the way its config struct does
+    fn deserialize(spn: SPN, kind: ConfigKind, config: &str) -> Status<Self> {
+        Ok(match kind {
+            ConfigKind::Double => ConfigValue::Double(deserialize_double(spn, config)?),
+            ConfigKind::Int => ConfigValue::Int(deserialize_int(spn, config)?),
+            ConfigKind::Bool => ConfigValue::Bool(deserialize_bool(spn, config)?),
+        })
+    }
+
+    /// Infers the type of a value from its text, `true` and `false` are bools,
+    /// whole numbers that fit an `i32` are ints and everything else numeric is a double
+    fn parse(text: &str) -> Option<Self> {
+        match text {
+            "true" => Some(ConfigValue::Bool(true)),
+            "false" => Some(ConfigValue::Bool(false)),
+            _ => text
+                .parse::<i32>()
+                .map(ConfigValue::Int)
+                .or_else(|_| text.parse::<f64>().map(ConfigValue::Double))
+                .ok(),
+        }
+    }
+}
+impl std::fmt::Display for ConfigValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigValue::Double(v) => write!(f, "{v}"),
+            ConfigValue::Int(v) => write!(f, "{v}"),
+            ConfigValue::Bool(v) => write!(f, "{v}"),
+        }
+    }
+}
+
+/// A single `id=value` pair of a device config
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConfigEntry {
+    /// The SPN of the value as the device sent it
+    pub id: u32,
+    /// The SPN of the value if this version of the crate knows it
+    pub spn: Option<SPN>,
+    pub value: ConfigValue,
+}
+
+/// Every config value a device reported, including the ones that
+/// have no typed field in this crates config structs.
+///
+/// Entries are parsed from the config string itself so values with an SPN
+/// unknown to this version of the crate are listed by their numeric id.
+/// The raw config string is kept so it can be written back with [`ConfigDump::serialize`].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ConfigDump {
+    pub entries: Vec<ConfigEntry>,
+    pub raw: String,
+}
+impl ConfigDump {
+    /// Returns the value reported for `spn` if the device reported it
+    pub fn get(&self, spn: SPN) -> Option<ConfigValue> {
+        self.get_id(i32::from(spn) as u32)
+    }
+
+    /// Returns the value reported for the numeric SPN `id`, this also finds SPNs unknown to the crate
+    pub fn get_id(&self, id: u32) -> Option<ConfigValue> {
+        self.entries
+            .iter()
+            .find(|entry| entry.id == id)
+            .map(|entry| entry.value)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &ConfigEntry> {
+        self.entries.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl std::fmt::Display for ConfigDump {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ConfigDump {{ ")?;
+        for (i, entry) in self.entries.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            match entry.spn {
+                Some(spn) => write!(f, "{spn:?}: {}", entry.value)?,
+                None => write!(f, "{}: {}", entry.id, entry.value)?,
+            }
+        }
+        write!(f, " }}")
+    }
+}
+
+seal! {ConfigDump}
+impl ConfigProtocol for ConfigDump {
+    fn serialize(&self) -> Status<String> {
+        Ok(self.raw.clone())
+    }
+
+    fn deserialize(to_deserialize: &str) -> Status<Self> {
+        // the config string is a list of `id=value;` pairs, values of SPNs the config
+        // structs read are typed by their SPN, the type of any other value is inferred
+        // from its text and pairs that do not parse are left in `raw` only
+        let entries = to_deserialize
+            .split(';')
+            .filter_map(|pair| {
+                let (id, value) = pair.split_once('=')?;
+                let id: u32 = id.trim().parse().ok()?;
+                let spn = i32::try_from(id).ok().and_then(|id| SPN::try_from(id).ok());
+                let known = spn.and_then(|spn| {
+                    let kind = ConfigKind::of(spn)?;
+                    ConfigValue::deserialize(spn, kind, to_deserialize).ok()
+                });
+                Some(ConfigEntry {
+                    id,
+                    spn,
+                    value: known.or_else(|| ConfigValue::parse(value.trim()))?,
+                })
+            })
+            .collect();
+        Ok(Self {
+            entries,
+            raw: to_deserialize.to_owned(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::devices::config_native::{serialize_bool, serialize_double, serialize_int};
+
+    use super::*;
+
+    #[test]
+    fn deserialize_keeps_unknown_spns() {
+        let (int, double) = (65000, 65001);
+        assert!(SPN::try_from(int as i32).is_err());
+        assert!(SPN::try_from(double as i32).is_err());
+        let raw = format!("{int}=42;{double}=1.5;");
+
+        let dump = ConfigDump::deserialize(&raw).unwrap();
+
+        assert_eq!(dump.len(), 2);
+        let entry = dump.iter().find(|entry| entry.id == int).unwrap();
+        assert_eq!(entry.spn, None);
+        assert_eq!(entry.value, ConfigValue::Int(42));
+        assert_eq!(dump.get_id(double), Some(ConfigValue::Double(1.5)));
+        assert_eq!(dump.raw, raw);
+    }
+
+    #[test]
+    fn deserialize_types_known_spns_by_spn() {
+        let unknown = 65000;
+        let mut raw = String::new();
+        // whole doubles and ints written as doubles must not be read back as another type
+        raw.push_str(&serialize_double(SPN::CANCODER_MAGNET_OFFSET, 2.0).unwrap());
+        raw.push_str(&serialize_int(SPN::CUSTOM_PARAM0, 42).unwrap());
+        raw.push_str(&serialize_bool(SPN::PIGEON2_USE_COMPASS, true).unwrap());
+        raw.push_str(&format!("{unknown}=1.5;"));
+
+        let dump = ConfigDump::deserialize(&raw).unwrap();
+
+        assert_eq!(
+            dump.get(SPN::CANCODER_MAGNET_OFFSET),
+            Some(ConfigValue::Double(2.0))
+        );
+        assert_eq!(dump.get(SPN::CUSTOM_PARAM0), Some(ConfigValue::Int(42)));
+        assert_eq!(
+            dump.get(SPN::PIGEON2_USE_COMPASS),
+            Some(ConfigValue::Bool(true))
+        );
+        assert_eq!(dump.get_id(unknown), Some(ConfigValue::Double(1.5)));
+    }
+}
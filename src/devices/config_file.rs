@@ -0,0 +1,147 @@
+//! Reading and writing device configs from files, like per robot calibration kept in deploy files.
+//!
+//! The format is picked from the file extension, `.toml` for TOML and `.json` for JSON.
+//! Fields missing from a file keep their default value.
+
+use std::path::{Path, PathBuf};
+
+use serde::{de::DeserializeOwned, Serialize};
+
+#[derive(Debug, thiserror::Error)]
+pub enum ConfigFileError {
+    #[error("could not access the config file: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("unsupported config file extension for {0}, expected .toml or .json")]
+    UnsupportedExtension(PathBuf),
+    #[error("could not parse the TOML config: {0}")]
+    TomlDeserialize(#[from] toml::de::Error),
+    #[error("could not write the TOML config: {0}")]
+    TomlSerialize(#[from] toml::ser::Error),
+    #[error("could not read or write the JSON config: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+enum ConfigFormat {
+    Toml,
+    Json,
+}
+impl ConfigFormat {
+    fn of(path: &Path) -> Result<Self, ConfigFileError> {
+        match path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(str::to_ascii_lowercase)
+            .as_deref()
+        {
+            Some("toml") => Ok(ConfigFormat::Toml),
+            Some("json") => Ok(ConfigFormat::Json),
+            _ => Err(ConfigFileError::UnsupportedExtension(path.to_owned())),
+        }
+    }
+}
+
+/// Reads a config from a `.toml` or `.json` file
+pub fn load_config<C: DeserializeOwned>(path: impl AsRef<Path>) -> Result<C, ConfigFileError> {
+    let path = path.as_ref();
+    let format = ConfigFormat::of(path)?;
+    let contents = std::fs::read_to_string(path)?;
+    Ok(match format {
+        ConfigFormat::Toml => toml::from_str(&contents)?,
+        ConfigFormat::Json => serde_json::from_str(&contents)?,
+    })
+}
+
+/// Writes a config to a `.toml` or `.json` file, replacing the file if it exists
+pub fn save_config<C: Serialize>(
+    config: &C,
+    path: impl AsRef<Path>,
+) -> Result<(), ConfigFileError> {
+    let path = path.as_ref();
+    let contents = match ConfigFormat::of(path)? {
+        ConfigFormat::Toml => toml::to_string_pretty(config)?,
+        ConfigFormat::Json => serde_json::to_string_pretty(config)?,
+    };
+    std::fs::write(path, contents)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use frclib_core::units::angle::{Degree, Rotation};
+
+    use crate::devices::{
+        cancoder::config::{
+            AbsoluteSensorRangeValue, CanCoderConfig, MagnetSensorConfigs, SensorDirectionValue,
+        },
+        pigeon::config::Pigeon2Configuration,
+    };
+
+    use super::*;
+
+    /// A path in a directory owned by this test process
+    fn temp_path(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("phoenix6-config-file-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir.join(name)
+    }
+
+    fn cancoder_config() -> CanCoderConfig {
+        CanCoderConfig {
+            future_proof_configs: false,
+            magnet_sensor: MagnetSensorConfigs {
+                sensor_direction: SensorDirectionValue::ClockwisePositive,
+                magnet_offset: Rotation::new(-0.25),
+                absolute_sensor_range: AbsoluteSensorRangeValue::Unsigned0To1,
+            },
+        }
+    }
+
+    fn pigeon_config() -> Pigeon2Configuration {
+        let mut config = Pigeon2Configuration::default();
+        config.mount_pose.mount_pose_yaw = Degree::new(90.0);
+        config.mount_pose.mount_pose_roll = Degree::new(-12.5);
+        config.gyro_trim.gyro_scalar_z = 1.5;
+        config.features.enable_compass = true;
+        config
+    }
+
+    #[test]
+    fn cancoder_round_trip() {
+        for name in ["cancoder.toml", "cancoder.json"] {
+            let path = temp_path(name);
+            save_config(&cancoder_config(), &path).unwrap();
+            let loaded: CanCoderConfig = load_config(&path).unwrap();
+            assert_eq!(loaded, cancoder_config(), "{name}");
+        }
+    }
+
+    #[test]
+    fn pigeon_round_trip() {
+        for name in ["pigeon.toml", "pigeon.json"] {
+            let path = temp_path(name);
+            save_config(&pigeon_config(), &path).unwrap();
+            let loaded: Pigeon2Configuration = load_config(&path).unwrap();
+            assert_eq!(loaded, pigeon_config(), "{name}");
+        }
+    }
+
+    #[test]
+    fn missing_fields_keep_their_default() {
+        let path = temp_path("partial.toml");
+        std::fs::write(&path, "[gyro_trim]\ngyro_scalar_z = 1.5\n").unwrap();
+        let loaded: Pigeon2Configuration = load_config(&path).unwrap();
+        let mut expected = Pigeon2Configuration::default();
+        expected.gyro_trim.gyro_scalar_z = 1.5;
+        assert_eq!(loaded, expected);
+    }
+
+    #[test]
+    fn unsupported_extension() {
+        let path = temp_path("cancoder.yaml");
+        assert!(matches!(
+            save_config(&cancoder_config(), &path),
+            Err(ConfigFileError::UnsupportedExtension(_))
+        ));
+        assert!(!path.exists());
+    }
+}
@@ -3,6 +3,7 @@ use std::{ffi::CStr, os::raw::c_char, ptr};
 
 use crate::{
     error::{StatusCode, StatusCodeType},
+    signals::faults::Fault,
     spn::SPN,
     Status,
 };
@@ -101,6 +102,22 @@ pub fn set_config(
     override_if_duplicate: bool,
 ) -> Status<()> {
     let config_string = config.serialize()?;
+    set_config_raw(
+        &device,
+        &config_string,
+        timeout,
+        future_proof_configs,
+        override_if_duplicate,
+    )
+}
+
+pub fn set_config_raw(
+    device: &DeviceIdentifier,
+    config_string: &str,
+    timeout: f64,
+    future_proof_configs: bool,
+    override_if_duplicate: bool,
+) -> Status<()> {
     unsafe {
         ctre_phoenix6_sys::c_ctre_phoenix6_set_configs(
             0,
@@ -117,6 +134,25 @@ pub fn set_config(
     }
 }
 
+/// Actions (clearing faults, setting sensor positions, ...) are sent to the device
+/// as a single serialized value over the config path
+pub fn send_action(device: &DeviceIdentifier, spn: SPN, value: f64, timeout: f64) -> Status<()> {
+    let action = serialize_double(spn, value)?;
+    set_config_raw(device, &action, timeout, false, true)
+}
+
+pub fn clear_sticky_faults(device: &DeviceIdentifier, timeout: f64) -> Status<()> {
+    send_action(device, SPN::SPN_CLEAR_STICKY_FAULTS, 0.0, timeout)
+}
+
+pub fn clear_sticky_fault(device: &DeviceIdentifier, fault: Fault, timeout: f64) -> Status<()> {
+    if !fault.applies_to(device.model) {
+        return Err(StatusCode::InvalidModel);
+    }
+    let spn = fault.clear_spn().ok_or(StatusCode::FeatureNotSupported)?;
+    send_action(device, spn, 0.0, timeout)
+}
+
 pub fn get_config<T: ConfigProtocol>(device: DeviceIdentifier, timeout: f64) -> Status<T> {
     unsafe {
         let mut config: *mut ::std::os::raw::c_char = ptr::null_mut();
@@ -0,0 +1,158 @@
+use crate::{
+    controls::ControlRequest,
+    error::StatusCode,
+    signals::{
+        faults::Fault,
+        native,
+        types::{IsProlicensedValue, LicensingIsSeasonPassedValue},
+        SPNValue,
+    },
+    spn::SPN,
+    Status,
+};
+
+use super::{DeviceIdentifier, Model, ACTIVE_DEVICES};
+
+/// The licensing state of a single device
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct LicenseStatus {
+    pub pro: IsProlicensedValue,
+    pub season_pass: LicensingIsSeasonPassedValue,
+    /// The device is running a feature it is not licensed for,
+    /// this is the live [`Fault::UnlicensedFeatureInUse`] fault
+    pub unlicensed_feature_in_use: bool,
+}
+impl LicenseStatus {
+    pub fn is_pro_licensed(&self) -> bool {
+        self.pro == IsProlicensedValue::Licensed
+    }
+
+    pub fn is_season_passed(&self) -> bool {
+        self.season_pass == LicensingIsSeasonPassedValue::Licensed
+    }
+
+    /// Returns true if the device can use Pro features,
+    /// either through a Pro license or a Season Pass
+    pub fn has_pro_features(&self) -> bool {
+        self.is_pro_licensed() || self.is_season_passed()
+    }
+}
+impl std::fmt::Display for LicenseStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "LicenseStatus {{ pro: {}, season_pass: {}, unlicensed_feature_in_use: {} }}",
+            self.is_pro_licensed(),
+            self.is_season_passed(),
+            self.unlicensed_feature_in_use
+        )
+    }
+}
+
+pub(crate) fn license_status(device: &DeviceIdentifier, timeout: f64) -> Status<LicenseStatus> {
+    let [pro, season_pass, unlicensed] = native::request_signal_values(
+        native::SignalMeta {
+            can_bus: device.canbus.clone(),
+            timeout,
+        },
+        [
+            SPN::LICENSING_IS_PRO_LICENSED,
+            SPN::LICENSING_IS_SEASON_PASSED,
+            Fault::UnlicensedFeatureInUse.fault_spn(),
+        ]
+        .map(|spn| native::SignalSpecifier {
+            hash: device.hash.0,
+            spn,
+        }),
+    )?;
+    Ok(LicenseStatus {
+        pro: IsProlicensedValue::try_from_f64(pro.value)?,
+        season_pass: LicensingIsSeasonPassedValue::try_from_f64(season_pass.value)?,
+        unlicensed_feature_in_use: bool::try_from_f64(unlicensed.value)?,
+    })
+}
+
+/// The devices found by [`unlicensed_devices_for`]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct UnlicensedDevices {
+    /// Devices that reported a license that does not cover the request
+    pub unlicensed: Vec<DeviceIdentifier>,
+    /// Devices that did not report their license, these may be unlicensed too
+    pub failed: Vec<(DeviceIdentifier, StatusCode)>,
+}
+impl UnlicensedDevices {
+    /// Returns true if every device reported a license that covers the request
+    pub fn all_licensed(&self) -> bool {
+        self.unlicensed.is_empty() && self.failed.is_empty()
+    }
+}
+
+/// Returns true if a device needs a Pro license (or Season Pass) to apply `request`.
+///
+/// This is the case for FOC commutation, differential control and dynamic Motion Magic®.
+pub fn requires_pro(request: &ControlRequest) -> bool {
+    match request {
+        ControlRequest::CoastOut(_) => false,
+        ControlRequest::DifferentialDutyCycle(_) => true,
+        ControlRequest::DifferentialFollower(_) => true,
+        ControlRequest::DifferentialMotionMagicDutyCycle(_) => true,
+        ControlRequest::DifferentialMotionMagicVoltage(_) => true,
+        ControlRequest::DifferentialPositionDutyCycle(_) => true,
+        ControlRequest::DifferentialPositionVoltage(_) => true,
+        ControlRequest::DifferentialStrictFollower(_) => true,
+        ControlRequest::DifferentialVelocityDutyCycle(_) => true,
+        ControlRequest::DifferentialVelocityVoltage(_) => true,
+        ControlRequest::DifferentialVoltage(_) => true,
+        ControlRequest::DutyCycleOut(req) => req.enable_foc,
+        ControlRequest::DynamicMotionMagicDutyCycle(_) => true,
+        ControlRequest::DynamicMotionMagicTorqueCurrentFOC(_) => true,
+        ControlRequest::DynamicMotionMagicVoltage(_) => true,
+        ControlRequest::EmptyControl(_) => false,
+        ControlRequest::Follower(_) => false,
+        ControlRequest::MotionMagicDutyCycle(req) => req.enable_foc,
+        ControlRequest::MotionMagicTorqueCurrentFOC(_) => true,
+        ControlRequest::MotionMagicVelocityDutyCycle(req) => req.enable_foc,
+        ControlRequest::MotionMagicVelocityTorqueCurrentFOC(_) => true,
+        ControlRequest::MotionMagicVelocityVoltage(req) => req.enable_foc,
+        ControlRequest::MotionMagicVoltage(req) => req.enable_foc,
+        ControlRequest::MusicTone(_) => false,
+        ControlRequest::NeutralOut(_) => false,
+        ControlRequest::PositionDutyCycle(req) => req.enable_foc,
+        ControlRequest::PositionTorqueCurrentFOC(_) => true,
+        ControlRequest::PositionVoltage(req) => req.enable_foc,
+        ControlRequest::StaticBrake(_) => false,
+        ControlRequest::StrictFollower(_) => false,
+        ControlRequest::TorqueCurrentFOC(_) => true,
+        ControlRequest::VelocityDutyCycle(req) => req.enable_foc,
+        ControlRequest::VelocityTorqueCurrentFOC(_) => true,
+        ControlRequest::VelocityVoltage(req) => req.enable_foc,
+        ControlRequest::VoltageOut(req) => req.enable_foc,
+    }
+}
+
+/// Lists every active motor controller on `can_bus` that does not have the
+/// license `request` needs.
+///
+/// Every device is checked, the devices that did not report their license are
+/// returned with their error next to the unlicensed ones.
+/// Both lists are empty if `request` does not need a Pro license.
+pub fn unlicensed_devices_for(can_bus: &str, request: &ControlRequest) -> UnlicensedDevices {
+    let mut result = UnlicensedDevices::default();
+    if !requires_pro(request) {
+        return result;
+    }
+    let devices: Vec<DeviceIdentifier> = ACTIVE_DEVICES
+        .read()
+        .iter()
+        .filter(|dev| dev.canbus == can_bus && dev.model == Model::talon_fx())
+        .cloned()
+        .collect();
+    for device in devices {
+        match license_status(&device, crate::DEFAULT_TIMEOUT) {
+            Ok(status) if status.has_pro_features() => {}
+            Ok(_) => result.unlicensed.push(device),
+            Err(err) => result.failed.push((device, err)),
+        }
+    }
+    result
+}
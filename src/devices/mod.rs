@@ -1,6 +1,12 @@
 pub mod cancoder;
+pub mod config_dump;
+#[cfg(feature = "serde")]
+pub mod config_file;
 mod config_native;
+pub mod licensing;
 pub mod pigeon;
+pub mod talonfx;
+pub mod version;
 
 use std::collections::HashSet;
 
@@ -10,8 +16,13 @@ use parking_lot::RwLock;
 use crate::{
     __sealed::Sealed,
     error::{StatusCode, StatusCodeType},
+    signals::{native, SPNValue},
+    spn::SPN,
+    Status,
 };
 
+use self::version::FirmwareVersion;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Model {
     inner: &'static str,
@@ -145,6 +156,75 @@ pub trait ConfigProtocol: Sealed + Sized + Default + std::fmt::Display {
     }
 }
 
+/// Behavior shared by every CTRE device,
+/// this is object safe so devices of different models can be stored together.
+pub trait ParentDevice {
+    fn identifier(&self) -> &DeviceIdentifier;
+
+    fn model(&self) -> Model {
+        self.identifier().model
+    }
+
+    fn device_id(&self) -> DeviceCanID {
+        self.identifier().index
+    }
+
+    fn can_bus(&self) -> &str {
+        &self.identifier().canbus
+    }
+
+    /// Disables every status signal of the device that has not been given
+    /// an update frequency, reducing the bus utilization of the device.
+    ///
+    /// Signals that had an explicit `set_update_freq` applied keep their frequency.
+    fn optimize_bus_utilization(&self) -> Status<()> {
+        native::optimize_signals_keep_explicit(
+            native::SignalMeta {
+                can_bus: self.identifier().canbus.clone(),
+                timeout: crate::DEFAULT_TIMEOUT,
+            },
+            self.identifier().clone(),
+        )
+    }
+
+    /// Resends every status signal update frequency that has been requested for the device,
+    /// useful after the device has been power cycled
+    fn resend_update_frequencies(&self) -> Status<()> {
+        native::resend_freq_updates(
+            native::SignalMeta {
+                can_bus: self.identifier().canbus.clone(),
+                timeout: crate::DEFAULT_TIMEOUT,
+            },
+            self.identifier().clone(),
+        )
+    }
+
+    /// Reads the firmware version of the device
+    fn version(&self) -> Status<FirmwareVersion> {
+        let ret = native::request_signal_value_single(
+            native::SignalMeta {
+                can_bus: self.identifier().canbus.clone(),
+                timeout: crate::DEFAULT_TIMEOUT,
+            },
+            native::SignalSpecifier {
+                hash: self.identifier().hash.0,
+                spn: SPN::VERSION_FULL,
+            },
+        )?;
+        FirmwareVersion::try_from_f64(ret.value)
+    }
+
+    /// Checks that the firmware on the device is compatible with the phoenix library
+    fn check_compatibility(&self) -> Status<()> {
+        version::check_compatibility(self.identifier(), crate::DEFAULT_TIMEOUT)
+    }
+
+    /// Clears all sticky faults on the device
+    fn clear_sticky_faults(&self) -> Status<()> {
+        config_native::clear_sticky_faults(self.identifier(), crate::DEFAULT_TIMEOUT)
+    }
+}
+
 static ACTIVE_DEVICES: RwLock<Lazy<HashSet<DeviceIdentifier>>> =
     RwLock::new(Lazy::new(HashSet::new));
 
@@ -162,6 +242,39 @@ fn propose_device(dev_id: DeviceIdentifier) -> Result<(), StatusCode> {
 /// Removes the device from the active devices list
 fn close_device(dev_id: &DeviceIdentifier) {
     ACTIVE_DEVICES.write().remove(dev_id);
+    native::forget_update_freqs(dev_id);
+}
+
+/// Optimizes the bus utilization of every active device on `can_bus`,
+/// see [`ParentDevice::optimize_bus_utilization`].
+///
+/// Every device is attempted, the devices that failed are returned with their error.
+pub fn optimize_all(can_bus: &str) -> Result<(), Vec<(DeviceIdentifier, StatusCode)>> {
+    let devices: Vec<DeviceIdentifier> = ACTIVE_DEVICES
+        .read()
+        .iter()
+        .filter(|dev| dev.canbus == can_bus)
+        .cloned()
+        .collect();
+    let failures: Vec<(DeviceIdentifier, StatusCode)> = devices
+        .into_iter()
+        .filter_map(|dev| {
+            native::optimize_signals_keep_explicit(
+                native::SignalMeta {
+                    can_bus: dev.canbus.clone(),
+                    timeout: crate::DEFAULT_TIMEOUT,
+                },
+                dev.clone(),
+            )
+            .err()
+            .map(|err| (dev, err))
+        })
+        .collect();
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(failures)
+    }
 }
 
 #[doc(hidden)]
@@ -207,9 +320,10 @@ macro_rules! signal {
                             hash: self.identifier.hash.0,
                             spn: (ThisSyncFields::$field_name as i32).try_into().expect("Invalid SPN")
                         }
-                    )?;
-                    self.cache.write().[< $fn_name >] = ret;
-                    Ok(SignalValue::<$type>::from(ret))
+                    );
+                    let mut cache = self.cache.write();
+                    cache.[< $fn_name >].update(ret)?;
+                    Ok(SignalValue::<$type>::from(cache.[< $fn_name >]))
                 }
             }
         }
@@ -13,6 +13,8 @@ use crate::{
 pub trait PigeonConfigType: ConfigProtocol {}
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct Pigeon2Configuration {
     /// True if we should factory default newer unsupported configs,
     /// false to leave newer unsupported configs alone.
@@ -101,6 +103,8 @@ impl PigeonConfigType for Pigeon2Configuration {}
 /// that's desired and ensure the reported Yaw/Pitch/Roll is from the
 /// robot's reference.
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct MountPoseConfigs {
     /// The mounting calibration yaw-component
     ///
@@ -233,6 +237,8 @@ impl PigeonConfigType for MountPoseConfigs {}
 /// out-of-the-box, users can make use of this to make the Pigeon2 even
 /// more accurate for their application.
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct GyroTrimConfigs {
     /// The scalar x of the gyroscope.
     ///
@@ -350,6 +356,8 @@ impl ConfigProtocol for GyroTrimConfigs {
 impl PigeonConfigType for GyroTrimConfigs {}
 
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct Pigeon2FeaturesConfigs {
     /// Turns on or off the magnetometer fusing for 9-axis. FRC users are not
     /// recommended to turn this on, as the magnetic influence of the robot
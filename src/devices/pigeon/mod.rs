@@ -3,14 +3,26 @@ pub mod signals;
 
 use std::sync::Arc;
 
-use frclib_core::units::time::Time;
+use frclib_core::units::{
+    angle::{Angle, Degree},
+    time::{Second, Time},
+};
 use parking_lot::RwLock;
 
-use crate::Status;
+use crate::{signals::faults::Fault, spn::SPN, Status};
 
-use self::{config::PigeonConfigType, signals::PigeonCache};
+use self::{
+    config::{Pigeon2Configuration, PigeonConfigType},
+    signals::PigeonCache,
+};
 
-use super::{config_native, propose_device, DeviceCanID, DeviceIdentifier};
+use super::{
+    close_device,
+    config_dump::ConfigDump,
+    config_native,
+    licensing::{self, LicenseStatus},
+    propose_device, version, DeviceCanID, DeviceIdentifier, ParentDevice,
+};
 
 //do not implement clone for this
 #[derive(Debug)]
@@ -33,6 +45,85 @@ impl Pigeon2 {
             identifier: &mut self.identifier,
         }
     }
+
+    /// Sets the yaw of the device, the write is confirmed by the device
+    /// before this returns.
+    ///
+    /// Reading the cached `yaw` signal fails with
+    /// [`StatusCode::SigNotUpdated`](crate::error::StatusCode::SigNotUpdated) until it is refreshed.
+    pub fn set_yaw(&self, yaw: impl Angle) -> Status<()> {
+        self.set_yaw_timeout(yaw, Second::new(crate::DEFAULT_TIMEOUT))
+    }
+
+    /// Sets the yaw of the device, the write is confirmed by the device
+    /// before this returns.
+    pub fn set_yaw_timeout(&self, yaw: impl Angle, timeout: impl Time) -> Status<()> {
+        let yaw: Degree = yaw.standard().into();
+        config_native::send_action(
+            &self.identifier,
+            SPN::PIGEON2_SET_YAW,
+            yaw.value(),
+            timeout.to_seconds().value(),
+        )?;
+        //the cached yaw is stale now
+        self.cache.write().invalidate_yaw();
+        Ok(())
+    }
+
+    /// Checks that the firmware on the device is compatible with the phoenix library
+    /// this crate is running against, see [`ParentDevice::check_compatibility`].
+    ///
+    /// A mismatch is reported as [`StatusCode::FirmwareTooOld`](crate::error::StatusCode::FirmwareTooOld),
+    /// [`StatusCode::FirmwareTooNew`](crate::error::StatusCode::FirmwareTooNew)
+    /// or [`StatusCode::ApiTooOld`](crate::error::StatusCode::ApiTooOld).
+    pub fn check_compatibility_timeout(&self, timeout: impl Time) -> Status<()> {
+        version::check_compatibility(&self.identifier, timeout.to_seconds().value())
+    }
+
+    /// Reads the Pro and Season Pass licensing of the device
+    /// and whether it is using a feature it is not licensed for
+    pub fn license_status(&self) -> Status<LicenseStatus> {
+        licensing::license_status(&self.identifier, crate::DEFAULT_TIMEOUT)
+    }
+
+    /// Reads the Pro and Season Pass licensing of the device
+    /// and whether it is using a feature it is not licensed for
+    pub fn license_status_timeout(&self, timeout: impl Time) -> Status<LicenseStatus> {
+        licensing::license_status(&self.identifier, timeout.to_seconds().value())
+    }
+
+    /// Clears all sticky faults on the device, see [`ParentDevice::clear_sticky_faults`]
+    pub fn clear_sticky_faults_timeout(&self, timeout: impl Time) -> Status<()> {
+        config_native::clear_sticky_faults(&self.identifier, timeout.to_seconds().value())
+    }
+
+    /// Clears a single sticky fault on the device.
+    ///
+    /// Returns [`StatusCode::InvalidModel`](crate::error::StatusCode::InvalidModel)
+    /// if this device can not report `fault`.
+    pub fn clear_sticky_fault(&self, fault: Fault) -> Status<()> {
+        config_native::clear_sticky_fault(&self.identifier, fault, crate::DEFAULT_TIMEOUT)
+    }
+
+    /// Clears a single sticky fault on the device.
+    ///
+    /// Returns [`StatusCode::InvalidModel`](crate::error::StatusCode::InvalidModel)
+    /// if this device can not report `fault`.
+    pub fn clear_sticky_fault_timeout(&self, fault: Fault, timeout: impl Time) -> Status<()> {
+        config_native::clear_sticky_fault(&self.identifier, fault, timeout.to_seconds().value())
+    }
+}
+
+impl Drop for Pigeon2 {
+    fn drop(&mut self) {
+        close_device(&self.identifier);
+    }
+}
+
+impl ParentDevice for Pigeon2 {
+    fn identifier(&self) -> &DeviceIdentifier {
+        &self.identifier
+    }
 }
 
 pub struct PigeonConfigurator<'hw> {
@@ -41,11 +132,12 @@ pub struct PigeonConfigurator<'hw> {
 }
 impl PigeonConfigurator<'_> {
     pub fn apply_config(&mut self, config: impl PigeonConfigType) -> Status<()> {
+        let fpc = config.future_proof_configs();
         config_native::set_config(
             self.identifier.clone(),
             config,
             crate::DEFAULT_TIMEOUT,
-            true,
+            fpc,
             true,
         )
     }
@@ -54,18 +146,76 @@ impl PigeonConfigurator<'_> {
         config: impl PigeonConfigType,
         timeout: impl Time,
     ) -> Status<()> {
+        let fpc = config.future_proof_configs();
         config_native::set_config(
             self.identifier.clone(),
             config,
             timeout.to_seconds().value(),
+            fpc,
             true,
-            true,
         )
     }
+    /// Applies the config without blocking the async executor
+    #[cfg(feature = "async")]
+    pub async fn apply_config_async(
+        &mut self,
+        config: impl PigeonConfigType + Send + 'static,
+    ) -> Status<()> {
+        self.apply_config_timeout_async(
+            config,
+            frclib_core::units::time::Second::new(crate::DEFAULT_TIMEOUT),
+        )
+        .await
+    }
+    /// Applies the config without blocking the async executor
+    #[cfg(feature = "async")]
+    pub async fn apply_config_timeout_async(
+        &mut self,
+        config: impl PigeonConfigType + Send + 'static,
+        timeout: impl Time,
+    ) -> Status<()> {
+        let fpc = config.future_proof_configs();
+        let identifier = self.identifier.clone();
+        let timeout = timeout.to_seconds().value();
+        crate::blocking::run(move || {
+            config_native::set_config(identifier, config, timeout, fpc, true)
+        })
+        .await
+    }
     pub fn get_config<T: PigeonConfigType>(&self) -> Status<T> {
         config_native::get_config(self.identifier.clone(), crate::DEFAULT_TIMEOUT)
     }
     pub fn get_config_timeout<T: PigeonConfigType>(&self, timeout: impl Time) -> Status<T> {
         config_native::get_config(self.identifier.clone(), timeout.to_seconds().value())
     }
+    /// Resets every config on the device to its factory default,
+    /// including configs this version of the crate does not know about.
+    pub fn factory_default(&mut self) -> Status<()> {
+        config_native::set_config(
+            self.identifier.clone(),
+            Pigeon2Configuration::default(),
+            crate::DEFAULT_TIMEOUT,
+            true,
+            true,
+        )
+    }
+    /// Resets every config on the device to its factory default,
+    /// including configs this version of the crate does not know about.
+    pub fn factory_default_timeout(&mut self, timeout: impl Time) -> Status<()> {
+        config_native::set_config(
+            self.identifier.clone(),
+            Pigeon2Configuration::default(),
+            timeout.to_seconds().value(),
+            true,
+            true,
+        )
+    }
+    /// Reads back every config value the device reports
+    pub fn refresh_all(&self) -> Status<ConfigDump> {
+        config_native::get_config(self.identifier.clone(), crate::DEFAULT_TIMEOUT)
+    }
+    /// Reads back every config value the device reports
+    pub fn refresh_all_timeout(&self, timeout: impl Time) -> Status<ConfigDump> {
+        config_native::get_config(self.identifier.clone(), timeout.to_seconds().value())
+    }
 }
@@ -1,18 +1,18 @@
 use std::sync::{Arc, Weak as Aweak};
 
-use frclib_core::units::{
-    angle::{Degree, Rotation},
-    angular_velocity::DegreePerSec,
-    energy::Volt,
-};
+use frclib_core::units::{angle::Degree, angular_velocity::DegreePerSec, energy::Volt};
 use parking_lot::RwLock;
 
 use crate::{
     cold_signal,
-    devices::DeviceIdentifier,
+    devices::{version::FirmwareVersion, DeviceIdentifier},
     error::StatusCode,
     signal, signal_setup,
-    signals::{native, BaseSignal, RefreshableStatusSignal, SPNValue, SignalValue, SignalValueRaw},
+    signals::{
+        faults::{FaultSet, StickyFaultSet},
+        native, BaseSignal, CachedValue, RefreshableStatusSignal, SPNValue, SignalValue,
+        SignalValueRaw,
+    },
     spn::SPN,
     Status,
 };
@@ -21,30 +21,36 @@ use super::Pigeon2;
 
 #[derive(Debug, Default)]
 pub(super) struct PigeonCache {
-    yaw: SignalValueRaw,
-    pitch: SignalValueRaw,
-    roll: SignalValueRaw,
-    quat_w: SignalValueRaw,
-    quat_x: SignalValueRaw,
-    quat_y: SignalValueRaw,
-    quat_z: SignalValueRaw,
-    gravity_x: SignalValueRaw,
-    gravity_y: SignalValueRaw,
-    gravity_z: SignalValueRaw,
-    temp: SignalValueRaw,
-    accum_gyro_x: SignalValueRaw,
-    accum_gyro_y: SignalValueRaw,
-    accum_gyro_z: SignalValueRaw,
-    angular_velocity_x: SignalValueRaw,
-    angular_velocity_y: SignalValueRaw,
-    angular_velocity_z: SignalValueRaw,
-    angular_velocity_x_world: SignalValueRaw,
-    angular_velocity_y_world: SignalValueRaw,
-    angular_velocity_z_world: SignalValueRaw,
-    accel_x: SignalValueRaw,
-    accel_y: SignalValueRaw,
-    accel_z: SignalValueRaw,
-    supply_voltage: SignalValueRaw,
+    yaw: CachedValue,
+    pitch: CachedValue,
+    roll: CachedValue,
+    quat_w: CachedValue,
+    quat_x: CachedValue,
+    quat_y: CachedValue,
+    quat_z: CachedValue,
+    gravity_x: CachedValue,
+    gravity_y: CachedValue,
+    gravity_z: CachedValue,
+    temp: CachedValue,
+    accum_gyro_x: CachedValue,
+    accum_gyro_y: CachedValue,
+    accum_gyro_z: CachedValue,
+    angular_velocity_x: CachedValue,
+    angular_velocity_y: CachedValue,
+    angular_velocity_z: CachedValue,
+    angular_velocity_x_world: CachedValue,
+    angular_velocity_y_world: CachedValue,
+    angular_velocity_z_world: CachedValue,
+    accel_x: CachedValue,
+    accel_y: CachedValue,
+    accel_z: CachedValue,
+    supply_voltage: CachedValue,
+}
+impl PigeonCache {
+    pub(super) fn invalidate_yaw(&mut self) {
+        // reads fail until a refresh returns a sample from after the write
+        self.yaw.invalidate();
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -75,6 +81,11 @@ pub enum PigeonSignalField {
     AccelZ = SPN::PIGEON2_ACCELERATIONZ as i32,
     SupplyVoltage = SPN::PIGEON2_SUPPLY_VOLTAGE as i32,
     IsPro = SPN::LICENSING_IS_PRO_LICENSED as i32,
+    IsSeasonPassed = SPN::LICENSING_IS_SEASON_PASSED as i32,
+    AllFaults = SPN::ALL_FAULTS as i32,
+    AllStickyFaults = SPN::ALL_STICKY_FAULTS as i32,
+    VersionFull = SPN::VERSION_FULL as i32,
+    CompliancyVersion = SPN::COMPLIANCY_VERSION as i32,
     //(sticky)faults
     StickyFaultHardware = SPN::STICKY_FAULT_HARDWARE as i32,
     FaultHardware = SPN::FAULT_HARDWARE as i32,
@@ -166,7 +177,7 @@ impl<T: SPNValue> BaseSignal<T> for PigeonSignal<T> {
                 PigeonSignalField::SupplyVoltage => cache.supply_voltage,
                 _ => unreachable!("This should not happen, this is a cold signal."),
             };
-            Ok(SignalValue::<T>::from(value))
+            value.read()
         } else {
             let ret = native::request_signal_value_single(
                 native::SignalMeta {
@@ -205,7 +216,7 @@ impl<T: SPNValue> BaseSignal<T> for PigeonSignal<T> {
     }
 }
 
-impl<T: SPNValue> RefreshableStatusSignal<T> for PigeonSignal<T> {
+impl<T: SPNValue> RefreshableStatusSignal for PigeonSignal<T> {
     fn refresh(&self) -> Status<()> {
         if self.cache.is_none() {
             return Ok(());
@@ -219,41 +230,52 @@ impl<T: SPNValue> RefreshableStatusSignal<T> for PigeonSignal<T> {
                 hash: self.identifier.hash.0,
                 spn: self.get_spn(),
             },
-        )?;
-        let cache = self
-            .cache
-            .as_ref()
-            .and_then(|c| c.upgrade())
-            .ok_or(StatusCode::CouldNotValidate)?;
+        );
+        self.update_cache(ret)
+    }
+
+    fn device_identifier(&self) -> &DeviceIdentifier {
+        &self.identifier
+    }
+
+    fn spn(&self) -> SPN {
+        self.get_spn()
+    }
+
+    fn update_cache(&self, value: Status<SignalValueRaw>) -> Status<()> {
+        let Some(cache) = &self.cache else {
+            return value.map(|_| ());
+        };
+        let cache = cache.upgrade().ok_or(StatusCode::CouldNotValidate)?;
         let mut cache = cache.write();
-        match self.field {
-            PigeonSignalField::Yaw => cache.yaw = ret,
-            PigeonSignalField::Pitch => cache.pitch = ret,
-            PigeonSignalField::Roll => cache.roll = ret,
-            PigeonSignalField::QuatW => cache.quat_w = ret,
-            PigeonSignalField::QuatX => cache.quat_x = ret,
-            PigeonSignalField::QuatY => cache.quat_y = ret,
-            PigeonSignalField::QuatZ => cache.quat_z = ret,
-            PigeonSignalField::GravityX => cache.gravity_x = ret,
-            PigeonSignalField::GravityY => cache.gravity_y = ret,
-            PigeonSignalField::GravityZ => cache.gravity_z = ret,
-            PigeonSignalField::Temp => cache.temp = ret,
-            PigeonSignalField::AccumGyroX => cache.accum_gyro_x = ret,
-            PigeonSignalField::AccumGyroY => cache.accum_gyro_y = ret,
-            PigeonSignalField::AccumGyroZ => cache.accum_gyro_z = ret,
-            PigeonSignalField::AngularVelocityX => cache.angular_velocity_x = ret,
-            PigeonSignalField::AngularVelocityY => cache.angular_velocity_y = ret,
-            PigeonSignalField::AngularVelocityZ => cache.angular_velocity_z = ret,
-            PigeonSignalField::AngularVelocityXWorld => cache.angular_velocity_x_world = ret,
-            PigeonSignalField::AngularVelocityYWorld => cache.angular_velocity_y_world = ret,
-            PigeonSignalField::AngularVelocityZWorld => cache.angular_velocity_z_world = ret,
-            PigeonSignalField::AccelX => cache.accel_x = ret,
-            PigeonSignalField::AccelY => cache.accel_y = ret,
-            PigeonSignalField::AccelZ => cache.accel_z = ret,
-            PigeonSignalField::SupplyVoltage => cache.supply_voltage = ret,
+        let entry = match self.field {
+            PigeonSignalField::Yaw => &mut cache.yaw,
+            PigeonSignalField::Pitch => &mut cache.pitch,
+            PigeonSignalField::Roll => &mut cache.roll,
+            PigeonSignalField::QuatW => &mut cache.quat_w,
+            PigeonSignalField::QuatX => &mut cache.quat_x,
+            PigeonSignalField::QuatY => &mut cache.quat_y,
+            PigeonSignalField::QuatZ => &mut cache.quat_z,
+            PigeonSignalField::GravityX => &mut cache.gravity_x,
+            PigeonSignalField::GravityY => &mut cache.gravity_y,
+            PigeonSignalField::GravityZ => &mut cache.gravity_z,
+            PigeonSignalField::Temp => &mut cache.temp,
+            PigeonSignalField::AccumGyroX => &mut cache.accum_gyro_x,
+            PigeonSignalField::AccumGyroY => &mut cache.accum_gyro_y,
+            PigeonSignalField::AccumGyroZ => &mut cache.accum_gyro_z,
+            PigeonSignalField::AngularVelocityX => &mut cache.angular_velocity_x,
+            PigeonSignalField::AngularVelocityY => &mut cache.angular_velocity_y,
+            PigeonSignalField::AngularVelocityZ => &mut cache.angular_velocity_z,
+            PigeonSignalField::AngularVelocityXWorld => &mut cache.angular_velocity_x_world,
+            PigeonSignalField::AngularVelocityYWorld => &mut cache.angular_velocity_y_world,
+            PigeonSignalField::AngularVelocityZWorld => &mut cache.angular_velocity_z_world,
+            PigeonSignalField::AccelX => &mut cache.accel_x,
+            PigeonSignalField::AccelY => &mut cache.accel_y,
+            PigeonSignalField::AccelZ => &mut cache.accel_z,
+            PigeonSignalField::SupplyVoltage => &mut cache.supply_voltage,
             _ => unreachable!("This should not happen, this is a cold signal."),
-        }
-        Ok(())
+        };
+        entry.update(value)
     }
 }
 
@@ -263,9 +285,9 @@ signal_setup! {
     fields: PigeonSignalField
 }
 
-signal! {yaw -> Yaw<Rotation>}
-signal! {pitch -> Pitch<Rotation>}
-signal! {roll -> Roll<Rotation>}
+signal! {yaw -> Yaw<Degree>}
+signal! {pitch -> Pitch<Degree>}
+signal! {roll -> Roll<Degree>}
 signal! {quat_w -> QuatW<f64>}
 signal! {quat_x -> QuatX<f64>}
 signal! {quat_y -> QuatY<f64>}
@@ -289,6 +311,11 @@ signal! {accel_z -> AccelZ<f64>}
 signal! {supply_voltage -> SupplyVoltage<Volt>}
 
 cold_signal! {is_pro -> IsPro<bool>}
+cold_signal! {is_season_passed -> IsSeasonPassed<bool>}
+cold_signal! {faults -> AllFaults<FaultSet>}
+cold_signal! {sticky_faults -> AllStickyFaults<StickyFaultSet>}
+cold_signal! {version -> VersionFull<FirmwareVersion>}
+cold_signal! {compliancy_version -> CompliancyVersion<i32>}
 
 cold_signal! {sticky_fault_hardware -> StickyFaultHardware<bool>}
 cold_signal! {fault_hardware -> FaultHardware<bool>}
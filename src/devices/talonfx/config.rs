@@ -0,0 +1,2323 @@
+use frclib_core::units::{
+    angle::Rotation,
+    angular_acceleration::RotationPerSecSqr,
+    angular_velocity::RotationPerSec,
+    energy::{Amp, Volt},
+    time::Second,
+};
+
+use crate::{
+    devices::{
+        config_native::{
+            deserialize_bool, deserialize_double, deserialize_int, serialize_bool,
+            serialize_double, serialize_int,
+        },
+        ConfigProtocol,
+    },
+    error::StatusCode,
+    seal,
+    spn::SPN,
+    Status,
+};
+
+pub trait TalonFXConfigType: ConfigProtocol {}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct TalonFXConfiguration {
+    /// True if we should factory default newer unsupported configs,
+    /// false to leave newer unsupported configs alone.
+    ///
+    /// This flag addresses a corner case where the device may have
+    /// firmware with newer configs that didn't exist when this
+    /// version of the API was built. If this occurs and this
+    /// flag is true, unsupported new configs will be factory
+    /// defaulted to avoid unexpected behavior.
+    ///
+    /// This is also the behavior in Phoenix 5, so this flag
+    /// is defaulted to true to match.
+    ///
+    pub future_proof_configs: bool,
+    /// Configs that directly affect motor output.
+    ///
+    /// Includes motor invert, neutral mode, and other features related to
+    /// motor output.
+    pub motor_output: MotorOutputConfigs,
+    /// Configs that directly affect current limiting features.
+    ///
+    /// Contains the supply/stator current limit thresholds and whether to
+    /// enable them.
+    pub current_limits: CurrentLimitsConfigs,
+    /// Voltage-specific configs.
+    ///
+    /// Voltage-specific configs, impacting voltage-based control modes.
+    pub voltage: VoltageConfigs,
+    /// Configs that affect Torque Current control types.
+    ///
+    /// Includes the maximum and minimum applied torque output and the neutral
+    /// deadband used during TorqueCurrentFOC requests.
+    pub torque_current: TorqueCurrentConfigs,
+    /// Configs that affect the feedback of this motor controller.
+    ///
+    /// Includes feedback sensor source, any offsets for the feedback sensor,
+    /// and various ratios to describe the relationship between the sensor and
+    /// the mechanism for closed looping.
+    pub feedback: FeedbackConfigs,
+    /// Configs related to sensors used for differential control of a
+    /// mechanism.
+    ///
+    /// Includes the differential sensor sources and IDs.
+    pub differential_sensors: DifferentialSensorsConfigs,
+    /// Configs related to constants used for differential control of a
+    /// mechanism.
+    ///
+    /// Includes the differential peak outputs.
+    pub differential_constants: DifferentialConstantsConfigs,
+    /// Configs that affect the open-loop control of this motor controller.
+    ///
+    /// Open-loop ramp rates for the various control types.
+    pub open_loop_ramps: OpenLoopRampsConfigs,
+    /// Configs that affect the closed-loop control of this motor controller.
+    ///
+    /// Closed-loop ramp rates for the various control types.
+    pub closed_loop_ramps: ClosedLoopRampsConfigs,
+    /// Configs that change how the motor controller behaves under different
+    /// limit switch states.
+    ///
+    /// Includes configs such as enabling limit switches, configuring the
+    /// remote sensor ID, the source, and the position to set on limit.
+    pub hardware_limit_switch: HardwareLimitSwitchConfigs,
+    /// Configs that affect audible components of the device.
+    ///
+    /// Includes configuration for the beep on boot.
+    pub audio: AudioConfigs,
+    /// Configs that affect how software-limit switches behave.
+    ///
+    /// Includes enabling software-limit switches and the threshold at which
+    /// they are tripped.
+    pub software_limit_switch: SoftwareLimitSwitchConfigs,
+    /// Configs for Motion Magic®.
+    ///
+    /// Includes Velocity, Acceleration, Jerk, and Expo parameters.
+    pub motion_magic: MotionMagicConfigs,
+    /// Custom Params.
+    ///
+    /// Custom paramaters that have no real impact on controller.
+    pub custom_params: CustomParamsConfigs,
+    /// Configs that affect general behavior during closed-looping.
+    ///
+    /// Includes Continuous Wrap features.
+    pub closed_loop_general: ClosedLoopGeneralConfigs,
+    /// Gains for the specified slot.
+    ///
+    /// If this slot is selected, these gains are used in closed loop control
+    /// requests.
+    pub slot0: Slot0Configs,
+    /// Gains for the specified slot.
+    ///
+    /// If this slot is selected, these gains are used in closed loop control
+    /// requests.
+    pub slot1: Slot1Configs,
+    /// Gains for the specified slot.
+    ///
+    /// If this slot is selected, these gains are used in closed loop control
+    /// requests.
+    pub slot2: Slot2Configs,
+}
+
+impl Default for TalonFXConfiguration {
+    fn default() -> Self {
+        Self {
+            future_proof_configs: true,
+            motor_output: MotorOutputConfigs::default(),
+            current_limits: CurrentLimitsConfigs::default(),
+            voltage: VoltageConfigs::default(),
+            torque_current: TorqueCurrentConfigs::default(),
+            feedback: FeedbackConfigs::default(),
+            differential_sensors: DifferentialSensorsConfigs::default(),
+            differential_constants: DifferentialConstantsConfigs::default(),
+            open_loop_ramps: OpenLoopRampsConfigs::default(),
+            closed_loop_ramps: ClosedLoopRampsConfigs::default(),
+            hardware_limit_switch: HardwareLimitSwitchConfigs::default(),
+            audio: AudioConfigs::default(),
+            software_limit_switch: SoftwareLimitSwitchConfigs::default(),
+            motion_magic: MotionMagicConfigs::default(),
+            custom_params: CustomParamsConfigs::default(),
+            closed_loop_general: ClosedLoopGeneralConfigs::default(),
+            slot0: Slot0Configs::default(),
+            slot1: Slot1Configs::default(),
+            slot2: Slot2Configs::default(),
+        }
+    }
+}
+
+impl std::fmt::Display for TalonFXConfiguration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "TalonFXConfiguration {{ future_proof_configs: {}, motor_output: {}, current_limits: {}, voltage: {}, torque_current: {}, feedback: {}, differential_sensors: {}, differential_constants: {}, open_loop_ramps: {}, closed_loop_ramps: {}, hardware_limit_switch: {}, audio: {}, software_limit_switch: {}, motion_magic: {}, custom_params: {}, closed_loop_general: {}, slot0: {}, slot1: {}, slot2: {} }}",
+            self.future_proof_configs,
+            self.motor_output,
+            self.current_limits,
+            self.voltage,
+            self.torque_current,
+            self.feedback,
+            self.differential_sensors,
+            self.differential_constants,
+            self.open_loop_ramps,
+            self.closed_loop_ramps,
+            self.hardware_limit_switch,
+            self.audio,
+            self.software_limit_switch,
+            self.motion_magic,
+            self.custom_params,
+            self.closed_loop_general,
+            self.slot0,
+            self.slot1,
+            self.slot2,
+        )
+    }
+}
+
+seal! {TalonFXConfiguration}
+impl ConfigProtocol for TalonFXConfiguration {
+    fn serialize(&self) -> Status<String> {
+        let mut ss = String::new();
+        ss.push_str(self.motor_output.serialize()?.as_ref());
+        ss.push_str(self.current_limits.serialize()?.as_ref());
+        ss.push_str(self.voltage.serialize()?.as_ref());
+        ss.push_str(self.torque_current.serialize()?.as_ref());
+        ss.push_str(self.feedback.serialize()?.as_ref());
+        ss.push_str(self.differential_sensors.serialize()?.as_ref());
+        ss.push_str(self.differential_constants.serialize()?.as_ref());
+        ss.push_str(self.open_loop_ramps.serialize()?.as_ref());
+        ss.push_str(self.closed_loop_ramps.serialize()?.as_ref());
+        ss.push_str(self.hardware_limit_switch.serialize()?.as_ref());
+        ss.push_str(self.audio.serialize()?.as_ref());
+        ss.push_str(self.software_limit_switch.serialize()?.as_ref());
+        ss.push_str(self.motion_magic.serialize()?.as_ref());
+        ss.push_str(self.custom_params.serialize()?.as_ref());
+        ss.push_str(self.closed_loop_general.serialize()?.as_ref());
+        ss.push_str(self.slot0.serialize()?.as_ref());
+        ss.push_str(self.slot1.serialize()?.as_ref());
+        ss.push_str(self.slot2.serialize()?.as_ref());
+        Ok(ss)
+    }
+
+    fn deserialize(to_deserialize: &str) -> Status<Self> {
+        Ok(Self {
+            motor_output: MotorOutputConfigs::deserialize(to_deserialize)?,
+            current_limits: CurrentLimitsConfigs::deserialize(to_deserialize)?,
+            voltage: VoltageConfigs::deserialize(to_deserialize)?,
+            torque_current: TorqueCurrentConfigs::deserialize(to_deserialize)?,
+            feedback: FeedbackConfigs::deserialize(to_deserialize)?,
+            differential_sensors: DifferentialSensorsConfigs::deserialize(to_deserialize)?,
+            differential_constants: DifferentialConstantsConfigs::deserialize(to_deserialize)?,
+            open_loop_ramps: OpenLoopRampsConfigs::deserialize(to_deserialize)?,
+            closed_loop_ramps: ClosedLoopRampsConfigs::deserialize(to_deserialize)?,
+            hardware_limit_switch: HardwareLimitSwitchConfigs::deserialize(to_deserialize)?,
+            audio: AudioConfigs::deserialize(to_deserialize)?,
+            software_limit_switch: SoftwareLimitSwitchConfigs::deserialize(to_deserialize)?,
+            motion_magic: MotionMagicConfigs::deserialize(to_deserialize)?,
+            custom_params: CustomParamsConfigs::deserialize(to_deserialize)?,
+            closed_loop_general: ClosedLoopGeneralConfigs::deserialize(to_deserialize)?,
+            slot0: Slot0Configs::deserialize(to_deserialize)?,
+            slot1: Slot1Configs::deserialize(to_deserialize)?,
+            slot2: Slot2Configs::deserialize(to_deserialize)?,
+            ..Default::default()
+        })
+    }
+
+    fn future_proof_configs(&self) -> bool {
+        self.future_proof_configs
+    }
+}
+impl TalonFXConfigType for TalonFXConfiguration {}
+
+/// Configs that directly affect motor output.
+///
+/// Includes motor invert, neutral mode, and other features related to
+/// motor output.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct MotorOutputConfigs {
+    /// Invert state of the device.
+    pub inverted: InvertedValue,
+    /// The state of the motor controller bridge when output is neutral or disabled.
+    pub neutral_mode: NeutralModeValue,
+    /// Configures the output deadband duty cycle during duty cycle and voltage
+    /// based control modes.
+    ///
+    /// Range: 0 to 0.25
+    pub duty_cycle_neutral_deadband: f64,
+    /// Maximum (forward) output during duty cycle based control modes.
+    ///
+    /// Range: -1 to 1
+    pub peak_forward_duty_cycle: f64,
+    /// Minimum (reverse) output during duty cycle based control modes.
+    ///
+    /// Range: -1 to 1
+    pub peak_reverse_duty_cycle: f64,
+}
+
+impl Default for MotorOutputConfigs {
+    fn default() -> Self {
+        Self {
+            inverted: InvertedValue::CounterClockwisePositive,
+            neutral_mode: NeutralModeValue::Coast,
+            duty_cycle_neutral_deadband: 0.0,
+            peak_forward_duty_cycle: 1.0,
+            peak_reverse_duty_cycle: -1.0,
+        }
+    }
+}
+
+impl std::fmt::Display for MotorOutputConfigs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "MotorOutputConfigs {{ inverted: {}, neutral_mode: {}, duty_cycle_neutral_deadband: {}, peak_forward_duty_cycle: {}, peak_reverse_duty_cycle: {} }}",
+            self.inverted,
+            self.neutral_mode,
+            self.duty_cycle_neutral_deadband,
+            self.peak_forward_duty_cycle,
+            self.peak_reverse_duty_cycle,
+        )
+    }
+}
+
+seal! {MotorOutputConfigs}
+impl ConfigProtocol for MotorOutputConfigs {
+    fn serialize(&self) -> Status<String> {
+        let mut ss = String::new();
+        ss.push_str(&serialize_int(SPN::CONFIG_INVERTED, self.inverted.into())?);
+        ss.push_str(&serialize_int(
+            SPN::CONFIG_NEUTRAL_MODE,
+            self.neutral_mode.into(),
+        )?);
+        ss.push_str(&serialize_double(
+            SPN::CONFIG_DUTY_CYCLE_NEUTRAL_DB,
+            self.duty_cycle_neutral_deadband,
+        )?);
+        ss.push_str(&serialize_double(
+            SPN::CONFIG_PEAK_FORWARD_DC,
+            self.peak_forward_duty_cycle,
+        )?);
+        ss.push_str(&serialize_double(
+            SPN::CONFIG_PEAK_REVERSE_DC,
+            self.peak_reverse_duty_cycle,
+        )?);
+        Ok(ss)
+    }
+
+    fn deserialize(to_deserialize: &str) -> Status<Self> {
+        Ok(Self {
+            inverted: deserialize_int(SPN::CONFIG_INVERTED, to_deserialize)?
+                .try_into()
+                .map_err(|_| StatusCode::CouldNotDeserializeString)?,
+            neutral_mode: deserialize_int(SPN::CONFIG_NEUTRAL_MODE, to_deserialize)?
+                .try_into()
+                .map_err(|_| StatusCode::CouldNotDeserializeString)?,
+            duty_cycle_neutral_deadband: deserialize_double(
+                SPN::CONFIG_DUTY_CYCLE_NEUTRAL_DB,
+                to_deserialize,
+            )?,
+            peak_forward_duty_cycle: deserialize_double(
+                SPN::CONFIG_PEAK_FORWARD_DC,
+                to_deserialize,
+            )?,
+            peak_reverse_duty_cycle: deserialize_double(
+                SPN::CONFIG_PEAK_REVERSE_DC,
+                to_deserialize,
+            )?,
+        })
+    }
+}
+impl TalonFXConfigType for MotorOutputConfigs {}
+
+/// Configs that directly affect current limiting features.
+///
+/// Contains the supply/stator current limit thresholds and whether to
+/// enable them.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct CurrentLimitsConfigs {
+    /// The amount of current allowed in the motor (motoring and regen
+    /// current). This is only applicable for non-torque current control
+    /// modes. Note this requires the corresponding enable to be true.
+    ///
+    /// Range: 0 to 800 A
+    pub stator_current_limit: Amp,
+    /// Enable motor stator current limiting.
+    pub stator_current_limit_enable: bool,
+    /// The amount of supply current allowed. This is only applicable for
+    /// non-torque current control modes. Note this requires the corresponding
+    /// enable to be true.
+    ///
+    /// Range: 0 to 800 A
+    pub supply_current_limit: Amp,
+    /// Enable motor supply current limiting.
+    pub supply_current_limit_enable: bool,
+    /// Delay supply current limiting until current exceeds this threshold
+    /// for longer than `supply_time_threshold`.
+    ///
+    /// Range: 0 to 511 A
+    pub supply_current_threshold: Amp,
+    /// Allows unlimited current for a period of time before current limiting
+    /// occurs. Current threshold is the maximum of `supply_current_threshold`
+    /// and `supply_current_limit`.
+    ///
+    /// Range: 0 to 1.275 seconds
+    pub supply_time_threshold: Second,
+}
+
+impl Default for CurrentLimitsConfigs {
+    fn default() -> Self {
+        Self {
+            stator_current_limit: Amp::from(0.0),
+            stator_current_limit_enable: false,
+            supply_current_limit: Amp::from(0.0),
+            supply_current_limit_enable: false,
+            supply_current_threshold: Amp::from(0.0),
+            supply_time_threshold: Second::from(0.0),
+        }
+    }
+}
+
+impl std::fmt::Display for CurrentLimitsConfigs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "CurrentLimitsConfigs {{ stator_current_limit: {}, stator_current_limit_enable: {}, supply_current_limit: {}, supply_current_limit_enable: {}, supply_current_threshold: {}, supply_time_threshold: {} }}",
+            self.stator_current_limit,
+            self.stator_current_limit_enable,
+            self.supply_current_limit,
+            self.supply_current_limit_enable,
+            self.supply_current_threshold,
+            self.supply_time_threshold,
+        )
+    }
+}
+
+seal! {CurrentLimitsConfigs}
+impl ConfigProtocol for CurrentLimitsConfigs {
+    fn serialize(&self) -> Status<String> {
+        let mut ss = String::new();
+        ss.push_str(&serialize_double(
+            SPN::CONFIG_STATOR_CURRENT_LIMIT,
+            self.stator_current_limit.value(),
+        )?);
+        ss.push_str(&serialize_bool(
+            SPN::CONFIG_STATOR_CURR_LIMIT_EN,
+            self.stator_current_limit_enable,
+        )?);
+        ss.push_str(&serialize_double(
+            SPN::CONFIG_SUPPLY_CURRENT_LIMIT,
+            self.supply_current_limit.value(),
+        )?);
+        ss.push_str(&serialize_bool(
+            SPN::CONFIG_SUPPLY_CURR_LIMIT_EN,
+            self.supply_current_limit_enable,
+        )?);
+        ss.push_str(&serialize_double(
+            SPN::CONFIG_SUPPLY_CURR_THRES,
+            self.supply_current_threshold.value(),
+        )?);
+        ss.push_str(&serialize_double(
+            SPN::CONFIG_SUPPLY_TIME_THRES,
+            self.supply_time_threshold.value(),
+        )?);
+        Ok(ss)
+    }
+
+    fn deserialize(to_deserialize: &str) -> Status<Self> {
+        Ok(Self {
+            stator_current_limit: Amp::from(deserialize_double(
+                SPN::CONFIG_STATOR_CURRENT_LIMIT,
+                to_deserialize,
+            )?),
+            stator_current_limit_enable: deserialize_bool(
+                SPN::CONFIG_STATOR_CURR_LIMIT_EN,
+                to_deserialize,
+            )?,
+            supply_current_limit: Amp::from(deserialize_double(
+                SPN::CONFIG_SUPPLY_CURRENT_LIMIT,
+                to_deserialize,
+            )?),
+            supply_current_limit_enable: deserialize_bool(
+                SPN::CONFIG_SUPPLY_CURR_LIMIT_EN,
+                to_deserialize,
+            )?,
+            supply_current_threshold: Amp::from(deserialize_double(
+                SPN::CONFIG_SUPPLY_CURR_THRES,
+                to_deserialize,
+            )?),
+            supply_time_threshold: Second::from(deserialize_double(
+                SPN::CONFIG_SUPPLY_TIME_THRES,
+                to_deserialize,
+            )?),
+        })
+    }
+}
+impl TalonFXConfigType for CurrentLimitsConfigs {}
+
+/// Voltage-specific configs.
+///
+/// Voltage-specific configs, impacting voltage-based control modes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct VoltageConfigs {
+    /// The time constant (in seconds) of the low-pass filter for the supply
+    /// voltage. This impacts the filtering for the reported supply voltage,
+    /// and any control strategies that use the supply voltage.
+    ///
+    /// Range: 0 to 0.1 seconds
+    pub supply_voltage_time_constant: Second,
+    /// Maximum (forward) output during voltage based control modes.
+    ///
+    /// Range: -16 to 16 V
+    pub peak_forward_voltage: Volt,
+    /// Minimum (reverse) output during voltage based control modes.
+    ///
+    /// Range: -16 to 16 V
+    pub peak_reverse_voltage: Volt,
+}
+
+impl Default for VoltageConfigs {
+    fn default() -> Self {
+        Self {
+            supply_voltage_time_constant: Second::from(0.0),
+            peak_forward_voltage: Volt::from(16.0),
+            peak_reverse_voltage: Volt::from(-16.0),
+        }
+    }
+}
+
+impl std::fmt::Display for VoltageConfigs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "VoltageConfigs {{ supply_voltage_time_constant: {}, peak_forward_voltage: {}, peak_reverse_voltage: {} }}",
+            self.supply_voltage_time_constant,
+            self.peak_forward_voltage,
+            self.peak_reverse_voltage,
+        )
+    }
+}
+
+seal! {VoltageConfigs}
+impl ConfigProtocol for VoltageConfigs {
+    fn serialize(&self) -> Status<String> {
+        let mut ss = String::new();
+        ss.push_str(&serialize_double(
+            SPN::CONFIG_SUPPLY_VLOWPASS_TAU,
+            self.supply_voltage_time_constant.value(),
+        )?);
+        ss.push_str(&serialize_double(
+            SPN::CONFIG_PEAK_FORWARDV,
+            self.peak_forward_voltage.value(),
+        )?);
+        ss.push_str(&serialize_double(
+            SPN::CONFIG_PEAK_REVERSEV,
+            self.peak_reverse_voltage.value(),
+        )?);
+        Ok(ss)
+    }
+
+    fn deserialize(to_deserialize: &str) -> Status<Self> {
+        Ok(Self {
+            supply_voltage_time_constant: Second::from(deserialize_double(
+                SPN::CONFIG_SUPPLY_VLOWPASS_TAU,
+                to_deserialize,
+            )?),
+            peak_forward_voltage: Volt::from(deserialize_double(
+                SPN::CONFIG_PEAK_FORWARDV,
+                to_deserialize,
+            )?),
+            peak_reverse_voltage: Volt::from(deserialize_double(
+                SPN::CONFIG_PEAK_REVERSEV,
+                to_deserialize,
+            )?),
+        })
+    }
+}
+impl TalonFXConfigType for VoltageConfigs {}
+
+/// Configs that affect Torque Current control types.
+///
+/// Includes the maximum and minimum applied torque output and the neutral
+/// deadband used during TorqueCurrentFOC requests.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct TorqueCurrentConfigs {
+    /// Maximum (forward) output during torque current based control modes.
+    ///
+    /// Range: -800 to 800 A
+    pub peak_forward_torque_current: Amp,
+    /// Minimum (reverse) output during torque current based control modes.
+    ///
+    /// Range: -800 to 800 A
+    pub peak_reverse_torque_current: Amp,
+    /// Configures the output deadband during torque current based control
+    /// modes.
+    ///
+    /// Range: 0 to 25 A
+    pub torque_neutral_deadband: Amp,
+}
+
+impl Default for TorqueCurrentConfigs {
+    fn default() -> Self {
+        Self {
+            peak_forward_torque_current: Amp::from(800.0),
+            peak_reverse_torque_current: Amp::from(-800.0),
+            torque_neutral_deadband: Amp::from(0.0),
+        }
+    }
+}
+
+impl std::fmt::Display for TorqueCurrentConfigs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "TorqueCurrentConfigs {{ peak_forward_torque_current: {}, peak_reverse_torque_current: {}, torque_neutral_deadband: {} }}",
+            self.peak_forward_torque_current,
+            self.peak_reverse_torque_current,
+            self.torque_neutral_deadband,
+        )
+    }
+}
+
+seal! {TorqueCurrentConfigs}
+impl ConfigProtocol for TorqueCurrentConfigs {
+    fn serialize(&self) -> Status<String> {
+        let mut ss = String::new();
+        ss.push_str(&serialize_double(
+            SPN::CONFIG_PEAK_FOR_TORQ_CURR,
+            self.peak_forward_torque_current.value(),
+        )?);
+        ss.push_str(&serialize_double(
+            SPN::CONFIG_PEAK_REV_TORQ_CURR,
+            self.peak_reverse_torque_current.value(),
+        )?);
+        ss.push_str(&serialize_double(
+            SPN::CONFIG_TORQUE_NEUTRAL_DB,
+            self.torque_neutral_deadband.value(),
+        )?);
+        Ok(ss)
+    }
+
+    fn deserialize(to_deserialize: &str) -> Status<Self> {
+        Ok(Self {
+            peak_forward_torque_current: Amp::from(deserialize_double(
+                SPN::CONFIG_PEAK_FOR_TORQ_CURR,
+                to_deserialize,
+            )?),
+            peak_reverse_torque_current: Amp::from(deserialize_double(
+                SPN::CONFIG_PEAK_REV_TORQ_CURR,
+                to_deserialize,
+            )?),
+            torque_neutral_deadband: Amp::from(deserialize_double(
+                SPN::CONFIG_TORQUE_NEUTRAL_DB,
+                to_deserialize,
+            )?),
+        })
+    }
+}
+impl TalonFXConfigType for TorqueCurrentConfigs {}
+
+/// Configs that affect the feedback of this motor controller.
+///
+/// Includes feedback sensor source, any offsets for the feedback sensor,
+/// and various ratios to describe the relationship between the sensor and
+/// the mechanism for closed looping.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct FeedbackConfigs {
+    /// This offset is applied to the absolute integrated rotor sensor. This
+    /// can be used to zero the rotor in applications that are within one
+    /// rotor rotation.
+    ///
+    /// Range: -1 to 1 rotations
+    pub feedback_rotor_offset: Rotation,
+    /// The ratio of sensor rotations to the mechanism's output. This is
+    /// equivalent to the mechanism's gear ratio if the sensor is located on
+    /// the input of a gearbox.
+    ///
+    /// Range: -1000 to 1000
+    pub sensor_to_mechanism_ratio: f64,
+    /// The ratio of motor rotor rotations to remote sensor rotations. This is
+    /// used by fused and sync feedback sources.
+    ///
+    /// Range: -1000 to 1000
+    pub rotor_to_sensor_ratio: f64,
+    /// Choose what sensor source is reported via API and used by closed-loop
+    /// and limit features.
+    pub feedback_sensor_source: FeedbackSensorSourceValue,
+    /// Device ID of which remote device to use. This is not used if the
+    /// sensor source is the internal rotor sensor.
+    ///
+    /// Range: 0 to 62
+    pub feedback_remote_sensor_id: i32,
+}
+
+impl Default for FeedbackConfigs {
+    fn default() -> Self {
+        Self {
+            feedback_rotor_offset: Rotation::from(0.0),
+            sensor_to_mechanism_ratio: 1.0,
+            rotor_to_sensor_ratio: 1.0,
+            feedback_sensor_source: FeedbackSensorSourceValue::RotorSensor,
+            feedback_remote_sensor_id: 0,
+        }
+    }
+}
+
+impl std::fmt::Display for FeedbackConfigs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "FeedbackConfigs {{ feedback_rotor_offset: {}, sensor_to_mechanism_ratio: {}, rotor_to_sensor_ratio: {}, feedback_sensor_source: {}, feedback_remote_sensor_id: {} }}",
+            self.feedback_rotor_offset,
+            self.sensor_to_mechanism_ratio,
+            self.rotor_to_sensor_ratio,
+            self.feedback_sensor_source,
+            self.feedback_remote_sensor_id,
+        )
+    }
+}
+
+seal! {FeedbackConfigs}
+impl ConfigProtocol for FeedbackConfigs {
+    fn serialize(&self) -> Status<String> {
+        let mut ss = String::new();
+        ss.push_str(&serialize_double(
+            SPN::CONFIG_FEEDBACK_ROTOR_OFFSET,
+            self.feedback_rotor_offset.value(),
+        )?);
+        ss.push_str(&serialize_double(
+            SPN::CONFIG_SENSOR_TO_MECHANISM_RATIO,
+            self.sensor_to_mechanism_ratio,
+        )?);
+        ss.push_str(&serialize_double(
+            SPN::CONFIG_ROTOR_TO_SENSOR_RATIO,
+            self.rotor_to_sensor_ratio,
+        )?);
+        ss.push_str(&serialize_int(
+            SPN::CONFIG_FEEDBACK_SENSOR_SOURCE,
+            self.feedback_sensor_source.into(),
+        )?);
+        ss.push_str(&serialize_int(
+            SPN::CONFIG_FEEDBACK_REMOTE_SENSOR_ID,
+            self.feedback_remote_sensor_id,
+        )?);
+        Ok(ss)
+    }
+
+    fn deserialize(to_deserialize: &str) -> Status<Self> {
+        Ok(Self {
+            feedback_rotor_offset: Rotation::from(deserialize_double(
+                SPN::CONFIG_FEEDBACK_ROTOR_OFFSET,
+                to_deserialize,
+            )?),
+            sensor_to_mechanism_ratio: deserialize_double(
+                SPN::CONFIG_SENSOR_TO_MECHANISM_RATIO,
+                to_deserialize,
+            )?,
+            rotor_to_sensor_ratio: deserialize_double(
+                SPN::CONFIG_ROTOR_TO_SENSOR_RATIO,
+                to_deserialize,
+            )?,
+            feedback_sensor_source: deserialize_int(
+                SPN::CONFIG_FEEDBACK_SENSOR_SOURCE,
+                to_deserialize,
+            )?
+            .try_into()
+            .map_err(|_| StatusCode::CouldNotDeserializeString)?,
+            feedback_remote_sensor_id: deserialize_int(
+                SPN::CONFIG_FEEDBACK_REMOTE_SENSOR_ID,
+                to_deserialize,
+            )?,
+        })
+    }
+}
+impl TalonFXConfigType for FeedbackConfigs {}
+
+/// Configs related to sensors used for differential control of a
+/// mechanism.
+///
+/// Includes the differential sensor sources and IDs.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct DifferentialSensorsConfigs {
+    /// Choose what sensor source is used for differential control of a
+    /// mechanism.
+    pub differential_sensor_source: DifferentialSensorSourceValue,
+    /// Device ID of which remote Talon FX to use. This is used when the
+    /// differential sensor source is not a remote CANcoder or Pigeon 2.
+    ///
+    /// Range: 0 to 62
+    pub differential_talon_fx_sensor_id: i32,
+    /// Device ID of which remote sensor to use on the differential axis. This
+    /// is used when the differential sensor source is a remote CANcoder or
+    /// Pigeon 2.
+    ///
+    /// Range: 0 to 62
+    pub differential_remote_sensor_id: i32,
+}
+
+impl Default for DifferentialSensorsConfigs {
+    fn default() -> Self {
+        Self {
+            differential_sensor_source: DifferentialSensorSourceValue::Disabled,
+            differential_talon_fx_sensor_id: 0,
+            differential_remote_sensor_id: 0,
+        }
+    }
+}
+
+impl std::fmt::Display for DifferentialSensorsConfigs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "DifferentialSensorsConfigs {{ differential_sensor_source: {}, differential_talon_fx_sensor_id: {}, differential_remote_sensor_id: {} }}",
+            self.differential_sensor_source,
+            self.differential_talon_fx_sensor_id,
+            self.differential_remote_sensor_id,
+        )
+    }
+}
+
+seal! {DifferentialSensorsConfigs}
+impl ConfigProtocol for DifferentialSensorsConfigs {
+    fn serialize(&self) -> Status<String> {
+        let mut ss = String::new();
+        ss.push_str(&serialize_int(
+            SPN::CONFIG_DIFFERENTIAL_SENSOR_SOURCE,
+            self.differential_sensor_source.into(),
+        )?);
+        ss.push_str(&serialize_int(
+            SPN::CONFIG_DIFFERENTIAL_TALON_FXSENSOR_ID,
+            self.differential_talon_fx_sensor_id,
+        )?);
+        ss.push_str(&serialize_int(
+            SPN::CONFIG_DIFFERENTIAL_REMOTE_SENSOR_ID,
+            self.differential_remote_sensor_id,
+        )?);
+        Ok(ss)
+    }
+
+    fn deserialize(to_deserialize: &str) -> Status<Self> {
+        Ok(Self {
+            differential_sensor_source: deserialize_int(
+                SPN::CONFIG_DIFFERENTIAL_SENSOR_SOURCE,
+                to_deserialize,
+            )?
+            .try_into()
+            .map_err(|_| StatusCode::CouldNotDeserializeString)?,
+            differential_talon_fx_sensor_id: deserialize_int(
+                SPN::CONFIG_DIFFERENTIAL_TALON_FXSENSOR_ID,
+                to_deserialize,
+            )?,
+            differential_remote_sensor_id: deserialize_int(
+                SPN::CONFIG_DIFFERENTIAL_REMOTE_SENSOR_ID,
+                to_deserialize,
+            )?,
+        })
+    }
+}
+impl TalonFXConfigType for DifferentialSensorsConfigs {}
+
+/// Configs related to constants used for differential control of a
+/// mechanism.
+///
+/// Includes the differential peak outputs.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct DifferentialConstantsConfigs {
+    /// Maximum differential output during duty cycle based differential
+    /// control modes.
+    ///
+    /// Range: 0 to 2
+    pub peak_differential_duty_cycle: f64,
+    /// Maximum differential output during voltage based differential control
+    /// modes.
+    ///
+    /// Range: 0 to 32 V
+    pub peak_differential_voltage: Volt,
+    /// Maximum differential output during torque current based differential
+    /// control modes.
+    ///
+    /// Range: 0 to 1600 A
+    pub peak_differential_torque_current: Amp,
+}
+
+impl Default for DifferentialConstantsConfigs {
+    fn default() -> Self {
+        Self {
+            peak_differential_duty_cycle: 2.0,
+            peak_differential_voltage: Volt::from(32.0),
+            peak_differential_torque_current: Amp::from(1600.0),
+        }
+    }
+}
+
+impl std::fmt::Display for DifferentialConstantsConfigs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "DifferentialConstantsConfigs {{ peak_differential_duty_cycle: {}, peak_differential_voltage: {}, peak_differential_torque_current: {} }}",
+            self.peak_differential_duty_cycle,
+            self.peak_differential_voltage,
+            self.peak_differential_torque_current,
+        )
+    }
+}
+
+seal! {DifferentialConstantsConfigs}
+impl ConfigProtocol for DifferentialConstantsConfigs {
+    fn serialize(&self) -> Status<String> {
+        let mut ss = String::new();
+        ss.push_str(&serialize_double(
+            SPN::CONFIG_PEAK_DIFF_DC,
+            self.peak_differential_duty_cycle,
+        )?);
+        ss.push_str(&serialize_double(
+            SPN::CONFIG_PEAK_DIFFV,
+            self.peak_differential_voltage.value(),
+        )?);
+        ss.push_str(&serialize_double(
+            SPN::CONFIG_PEAK_DIFF_TORQ_CURR,
+            self.peak_differential_torque_current.value(),
+        )?);
+        Ok(ss)
+    }
+
+    fn deserialize(to_deserialize: &str) -> Status<Self> {
+        Ok(Self {
+            peak_differential_duty_cycle: deserialize_double(
+                SPN::CONFIG_PEAK_DIFF_DC,
+                to_deserialize,
+            )?,
+            peak_differential_voltage: Volt::from(deserialize_double(
+                SPN::CONFIG_PEAK_DIFFV,
+                to_deserialize,
+            )?),
+            peak_differential_torque_current: Amp::from(deserialize_double(
+                SPN::CONFIG_PEAK_DIFF_TORQ_CURR,
+                to_deserialize,
+            )?),
+        })
+    }
+}
+impl TalonFXConfigType for DifferentialConstantsConfigs {}
+
+/// Configs that affect the open-loop control of this motor controller.
+///
+/// Open-loop ramp rates for the various control types.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct OpenLoopRampsConfigs {
+    /// If non-zero, this determines how much time to ramp from 0% output to
+    /// 100% during open-loop duty cycle control modes.
+    ///
+    /// Range: 0 to 1 seconds
+    pub duty_cycle_open_loop_ramp_period: Second,
+    /// If non-zero, this determines how much time to ramp from 0V output to
+    /// 12V during open-loop voltage control modes.
+    ///
+    /// Range: 0 to 1 seconds
+    pub voltage_open_loop_ramp_period: Second,
+    /// If non-zero, this determines how much time to ramp from 0A output to
+    /// 300A during open-loop torque current control modes.
+    ///
+    /// Range: 0 to 10 seconds
+    pub torque_open_loop_ramp_period: Second,
+}
+
+impl Default for OpenLoopRampsConfigs {
+    fn default() -> Self {
+        Self {
+            duty_cycle_open_loop_ramp_period: Second::from(0.0),
+            voltage_open_loop_ramp_period: Second::from(0.0),
+            torque_open_loop_ramp_period: Second::from(0.0),
+        }
+    }
+}
+
+impl std::fmt::Display for OpenLoopRampsConfigs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "OpenLoopRampsConfigs {{ duty_cycle_open_loop_ramp_period: {}, voltage_open_loop_ramp_period: {}, torque_open_loop_ramp_period: {} }}",
+            self.duty_cycle_open_loop_ramp_period,
+            self.voltage_open_loop_ramp_period,
+            self.torque_open_loop_ramp_period,
+        )
+    }
+}
+
+seal! {OpenLoopRampsConfigs}
+impl ConfigProtocol for OpenLoopRampsConfigs {
+    fn serialize(&self) -> Status<String> {
+        let mut ss = String::new();
+        ss.push_str(&serialize_double(
+            SPN::CONFIG_DUTY_CYCLE_OPEN_LOOP_RAMP_PERIOD,
+            self.duty_cycle_open_loop_ramp_period.value(),
+        )?);
+        ss.push_str(&serialize_double(
+            SPN::CONFIG_VOLTAGE_OPEN_LOOP_RAMP_PERIOD,
+            self.voltage_open_loop_ramp_period.value(),
+        )?);
+        ss.push_str(&serialize_double(
+            SPN::CONFIG_TORQUE_OPEN_LOOP_RAMP_PERIOD,
+            self.torque_open_loop_ramp_period.value(),
+        )?);
+        Ok(ss)
+    }
+
+    fn deserialize(to_deserialize: &str) -> Status<Self> {
+        Ok(Self {
+            duty_cycle_open_loop_ramp_period: Second::from(deserialize_double(
+                SPN::CONFIG_DUTY_CYCLE_OPEN_LOOP_RAMP_PERIOD,
+                to_deserialize,
+            )?),
+            voltage_open_loop_ramp_period: Second::from(deserialize_double(
+                SPN::CONFIG_VOLTAGE_OPEN_LOOP_RAMP_PERIOD,
+                to_deserialize,
+            )?),
+            torque_open_loop_ramp_period: Second::from(deserialize_double(
+                SPN::CONFIG_TORQUE_OPEN_LOOP_RAMP_PERIOD,
+                to_deserialize,
+            )?),
+        })
+    }
+}
+impl TalonFXConfigType for OpenLoopRampsConfigs {}
+
+/// Configs that affect the closed-loop control of this motor controller.
+///
+/// Closed-loop ramp rates for the various control types.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct ClosedLoopRampsConfigs {
+    /// If non-zero, this determines how much time to ramp from 0% output to
+    /// 100% during closed-loop duty cycle control modes.
+    ///
+    /// Range: 0 to 1 seconds
+    pub duty_cycle_closed_loop_ramp_period: Second,
+    /// If non-zero, this determines how much time to ramp from 0V output to
+    /// 12V during closed-loop voltage control modes.
+    ///
+    /// Range: 0 to 1 seconds
+    pub voltage_closed_loop_ramp_period: Second,
+    /// If non-zero, this determines how much time to ramp from 0A output to
+    /// 300A during closed-loop torque current control modes.
+    ///
+    /// Range: 0 to 10 seconds
+    pub torque_closed_loop_ramp_period: Second,
+}
+
+impl Default for ClosedLoopRampsConfigs {
+    fn default() -> Self {
+        Self {
+            duty_cycle_closed_loop_ramp_period: Second::from(0.0),
+            voltage_closed_loop_ramp_period: Second::from(0.0),
+            torque_closed_loop_ramp_period: Second::from(0.0),
+        }
+    }
+}
+
+impl std::fmt::Display for ClosedLoopRampsConfigs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ClosedLoopRampsConfigs {{ duty_cycle_closed_loop_ramp_period: {}, voltage_closed_loop_ramp_period: {}, torque_closed_loop_ramp_period: {} }}",
+            self.duty_cycle_closed_loop_ramp_period,
+            self.voltage_closed_loop_ramp_period,
+            self.torque_closed_loop_ramp_period,
+        )
+    }
+}
+
+seal! {ClosedLoopRampsConfigs}
+impl ConfigProtocol for ClosedLoopRampsConfigs {
+    fn serialize(&self) -> Status<String> {
+        let mut ss = String::new();
+        ss.push_str(&serialize_double(
+            SPN::CONFIG_DUTY_CYCLE_CLOSED_LOOP_RAMP_PERIOD,
+            self.duty_cycle_closed_loop_ramp_period.value(),
+        )?);
+        ss.push_str(&serialize_double(
+            SPN::CONFIG_VOLTAGE_CLOSED_LOOP_RAMP_PERIOD,
+            self.voltage_closed_loop_ramp_period.value(),
+        )?);
+        ss.push_str(&serialize_double(
+            SPN::CONFIG_TORQUE_CLOSED_LOOP_RAMP_PERIOD,
+            self.torque_closed_loop_ramp_period.value(),
+        )?);
+        Ok(ss)
+    }
+
+    fn deserialize(to_deserialize: &str) -> Status<Self> {
+        Ok(Self {
+            duty_cycle_closed_loop_ramp_period: Second::from(deserialize_double(
+                SPN::CONFIG_DUTY_CYCLE_CLOSED_LOOP_RAMP_PERIOD,
+                to_deserialize,
+            )?),
+            voltage_closed_loop_ramp_period: Second::from(deserialize_double(
+                SPN::CONFIG_VOLTAGE_CLOSED_LOOP_RAMP_PERIOD,
+                to_deserialize,
+            )?),
+            torque_closed_loop_ramp_period: Second::from(deserialize_double(
+                SPN::CONFIG_TORQUE_CLOSED_LOOP_RAMP_PERIOD,
+                to_deserialize,
+            )?),
+        })
+    }
+}
+impl TalonFXConfigType for ClosedLoopRampsConfigs {}
+
+/// Configs that change how the motor controller behaves under different
+/// limit switch states.
+///
+/// Includes configs such as enabling limit switches, configuring the
+/// remote sensor ID, the source, and the position to set on limit.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct HardwareLimitSwitchConfigs {
+    /// Determines if the forward limit switch is normally-open (default) or
+    /// normally-closed.
+    pub forward_limit_type: ForwardLimitTypeValue,
+    /// If enabled, the position is automatically set to a specific value,
+    /// specified by `forward_limit_autoset_position_value`, when the forward
+    /// limit switch is asserted.
+    pub forward_limit_autoset_position_enable: bool,
+    /// The value to automatically set the position to when the forward limit
+    /// switch is asserted. This has no effect if
+    /// `forward_limit_autoset_position_enable` is false.
+    ///
+    /// Range: -3.4e38 to 3.4e38 rotations
+    pub forward_limit_autoset_position_value: Rotation,
+    /// If enabled, motor output is set to neutral when the forward limit
+    /// switch is asserted and positive output is requested.
+    pub forward_limit_enable: bool,
+    /// Determines where to poll the forward limit switch. This defaults to
+    /// the limit switch pin on the limit switch connector.
+    pub forward_limit_source: ForwardLimitSourceValue,
+    /// Device ID of the device if `forward_limit_source` is set to a remote
+    /// device.
+    ///
+    /// Range: 0 to 62
+    pub forward_limit_remote_sensor_id: i32,
+    /// Determines if the reverse limit switch is normally-open (default) or
+    /// normally-closed.
+    pub reverse_limit_type: ReverseLimitTypeValue,
+    /// If enabled, the position is automatically set to a specific value,
+    /// specified by `reverse_limit_autoset_position_value`, when the reverse
+    /// limit switch is asserted.
+    pub reverse_limit_autoset_position_enable: bool,
+    /// The value to automatically set the position to when the reverse limit
+    /// switch is asserted. This has no effect if
+    /// `reverse_limit_autoset_position_enable` is false.
+    ///
+    /// Range: -3.4e38 to 3.4e38 rotations
+    pub reverse_limit_autoset_position_value: Rotation,
+    /// If enabled, motor output is set to neutral when the reverse limit
+    /// switch is asserted and negative output is requested.
+    pub reverse_limit_enable: bool,
+    /// Determines where to poll the reverse limit switch. This defaults to
+    /// the limit switch pin on the limit switch connector.
+    pub reverse_limit_source: ReverseLimitSourceValue,
+    /// Device ID of the device if `reverse_limit_source` is set to a remote
+    /// device.
+    ///
+    /// Range: 0 to 62
+    pub reverse_limit_remote_sensor_id: i32,
+}
+
+impl Default for HardwareLimitSwitchConfigs {
+    fn default() -> Self {
+        Self {
+            forward_limit_type: ForwardLimitTypeValue::NormallyOpen,
+            forward_limit_autoset_position_enable: false,
+            forward_limit_autoset_position_value: Rotation::from(0.0),
+            forward_limit_enable: true,
+            forward_limit_source: ForwardLimitSourceValue::LimitSwitchPin,
+            forward_limit_remote_sensor_id: 0,
+            reverse_limit_type: ReverseLimitTypeValue::NormallyOpen,
+            reverse_limit_autoset_position_enable: false,
+            reverse_limit_autoset_position_value: Rotation::from(0.0),
+            reverse_limit_enable: true,
+            reverse_limit_source: ReverseLimitSourceValue::LimitSwitchPin,
+            reverse_limit_remote_sensor_id: 0,
+        }
+    }
+}
+
+impl std::fmt::Display for HardwareLimitSwitchConfigs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "HardwareLimitSwitchConfigs {{ forward_limit_type: {}, forward_limit_autoset_position_enable: {}, forward_limit_autoset_position_value: {}, forward_limit_enable: {}, forward_limit_source: {}, forward_limit_remote_sensor_id: {}, reverse_limit_type: {}, reverse_limit_autoset_position_enable: {}, reverse_limit_autoset_position_value: {}, reverse_limit_enable: {}, reverse_limit_source: {}, reverse_limit_remote_sensor_id: {} }}",
+            self.forward_limit_type,
+            self.forward_limit_autoset_position_enable,
+            self.forward_limit_autoset_position_value,
+            self.forward_limit_enable,
+            self.forward_limit_source,
+            self.forward_limit_remote_sensor_id,
+            self.reverse_limit_type,
+            self.reverse_limit_autoset_position_enable,
+            self.reverse_limit_autoset_position_value,
+            self.reverse_limit_enable,
+            self.reverse_limit_source,
+            self.reverse_limit_remote_sensor_id,
+        )
+    }
+}
+
+seal! {HardwareLimitSwitchConfigs}
+impl ConfigProtocol for HardwareLimitSwitchConfigs {
+    fn serialize(&self) -> Status<String> {
+        let mut ss = String::new();
+        ss.push_str(&serialize_int(
+            SPN::CONFIG_FORWARD_LIMIT_TYPE,
+            self.forward_limit_type.into(),
+        )?);
+        ss.push_str(&serialize_bool(
+            SPN::CONFIG_FORWARD_LIMIT_AUTOSET_POS_ENABLE,
+            self.forward_limit_autoset_position_enable,
+        )?);
+        ss.push_str(&serialize_double(
+            SPN::CONFIG_FORWARD_LIMIT_AUTOSET_POS_VALUE,
+            self.forward_limit_autoset_position_value.value(),
+        )?);
+        ss.push_str(&serialize_bool(
+            SPN::CONFIG_FORWARD_LIMIT_ENABLE,
+            self.forward_limit_enable,
+        )?);
+        ss.push_str(&serialize_int(
+            SPN::CONFIG_FORWARD_LIMIT_SOURCE,
+            self.forward_limit_source.into(),
+        )?);
+        ss.push_str(&serialize_int(
+            SPN::CONFIG_FORWARD_LIMIT_REMOTE_SENSOR_ID,
+            self.forward_limit_remote_sensor_id,
+        )?);
+        ss.push_str(&serialize_int(
+            SPN::CONFIG_REVERSE_LIMIT_TYPE,
+            self.reverse_limit_type.into(),
+        )?);
+        ss.push_str(&serialize_bool(
+            SPN::CONFIG_REVERSE_LIMIT_AUTOSET_POS_ENABLE,
+            self.reverse_limit_autoset_position_enable,
+        )?);
+        ss.push_str(&serialize_double(
+            SPN::CONFIG_REVERSE_LIMIT_AUTOSET_POS_VALUE,
+            self.reverse_limit_autoset_position_value.value(),
+        )?);
+        ss.push_str(&serialize_bool(
+            SPN::CONFIG_REVERSE_LIMIT_ENABLE,
+            self.reverse_limit_enable,
+        )?);
+        ss.push_str(&serialize_int(
+            SPN::CONFIG_REVERSE_LIMIT_SOURCE,
+            self.reverse_limit_source.into(),
+        )?);
+        ss.push_str(&serialize_int(
+            SPN::CONFIG_REVERSE_LIMIT_REMOTE_SENSOR_ID,
+            self.reverse_limit_remote_sensor_id,
+        )?);
+        Ok(ss)
+    }
+
+    fn deserialize(to_deserialize: &str) -> Status<Self> {
+        Ok(Self {
+            forward_limit_type: deserialize_int(SPN::CONFIG_FORWARD_LIMIT_TYPE, to_deserialize)?
+                .try_into()
+                .map_err(|_| StatusCode::CouldNotDeserializeString)?,
+            forward_limit_autoset_position_enable: deserialize_bool(
+                SPN::CONFIG_FORWARD_LIMIT_AUTOSET_POS_ENABLE,
+                to_deserialize,
+            )?,
+            forward_limit_autoset_position_value: Rotation::from(deserialize_double(
+                SPN::CONFIG_FORWARD_LIMIT_AUTOSET_POS_VALUE,
+                to_deserialize,
+            )?),
+            forward_limit_enable: deserialize_bool(
+                SPN::CONFIG_FORWARD_LIMIT_ENABLE,
+                to_deserialize,
+            )?,
+            forward_limit_source: deserialize_int(
+                SPN::CONFIG_FORWARD_LIMIT_SOURCE,
+                to_deserialize,
+            )?
+            .try_into()
+            .map_err(|_| StatusCode::CouldNotDeserializeString)?,
+            forward_limit_remote_sensor_id: deserialize_int(
+                SPN::CONFIG_FORWARD_LIMIT_REMOTE_SENSOR_ID,
+                to_deserialize,
+            )?,
+            reverse_limit_type: deserialize_int(SPN::CONFIG_REVERSE_LIMIT_TYPE, to_deserialize)?
+                .try_into()
+                .map_err(|_| StatusCode::CouldNotDeserializeString)?,
+            reverse_limit_autoset_position_enable: deserialize_bool(
+                SPN::CONFIG_REVERSE_LIMIT_AUTOSET_POS_ENABLE,
+                to_deserialize,
+            )?,
+            reverse_limit_autoset_position_value: Rotation::from(deserialize_double(
+                SPN::CONFIG_REVERSE_LIMIT_AUTOSET_POS_VALUE,
+                to_deserialize,
+            )?),
+            reverse_limit_enable: deserialize_bool(
+                SPN::CONFIG_REVERSE_LIMIT_ENABLE,
+                to_deserialize,
+            )?,
+            reverse_limit_source: deserialize_int(
+                SPN::CONFIG_REVERSE_LIMIT_SOURCE,
+                to_deserialize,
+            )?
+            .try_into()
+            .map_err(|_| StatusCode::CouldNotDeserializeString)?,
+            reverse_limit_remote_sensor_id: deserialize_int(
+                SPN::CONFIG_REVERSE_LIMIT_REMOTE_SENSOR_ID,
+                to_deserialize,
+            )?,
+        })
+    }
+}
+impl TalonFXConfigType for HardwareLimitSwitchConfigs {}
+
+/// Configs that affect audible components of the device.
+///
+/// Includes configuration for the beep on boot.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct AudioConfigs {
+    /// If true, the TalonFX will beep during boot-up. This is useful for
+    /// general debugging, and defaults to true. If rotor is moving during
+    /// boot-up, the beep will not occur regardless of this setting.
+    pub beep_on_boot: bool,
+    /// If true, the TalonFX will beep during configuration API calls if
+    /// device is disabled. This is useful for general debugging, and defaults
+    /// to true. Note that if the rotor is moving, the beep will not occur
+    /// regardless of this setting.
+    pub beep_on_config: bool,
+    /// If true, the TalonFX will allow Orchestra and MusicTone requests during
+    /// disabled state. This can be used to address corner cases when music
+    /// features are needed when disabled. This setting defaults to false.
+    pub allow_music_dur_disable: bool,
+}
+
+impl Default for AudioConfigs {
+    fn default() -> Self {
+        Self {
+            beep_on_boot: true,
+            beep_on_config: true,
+            allow_music_dur_disable: false,
+        }
+    }
+}
+
+impl std::fmt::Display for AudioConfigs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "AudioConfigs {{ beep_on_boot: {}, beep_on_config: {}, allow_music_dur_disable: {} }}",
+            self.beep_on_boot, self.beep_on_config, self.allow_music_dur_disable,
+        )
+    }
+}
+
+seal! {AudioConfigs}
+impl ConfigProtocol for AudioConfigs {
+    fn serialize(&self) -> Status<String> {
+        let mut ss = String::new();
+        ss.push_str(&serialize_bool(
+            SPN::CONFIG_BEEP_ON_BOOT,
+            self.beep_on_boot,
+        )?);
+        ss.push_str(&serialize_bool(
+            SPN::CONFIG_BEEP_ON_CONFIG,
+            self.beep_on_config,
+        )?);
+        ss.push_str(&serialize_bool(
+            SPN::CONFIG_ALLOW_MUSIC_DUR_DISABLE,
+            self.allow_music_dur_disable,
+        )?);
+        Ok(ss)
+    }
+
+    fn deserialize(to_deserialize: &str) -> Status<Self> {
+        Ok(Self {
+            beep_on_boot: deserialize_bool(SPN::CONFIG_BEEP_ON_BOOT, to_deserialize)?,
+            beep_on_config: deserialize_bool(SPN::CONFIG_BEEP_ON_CONFIG, to_deserialize)?,
+            allow_music_dur_disable: deserialize_bool(
+                SPN::CONFIG_ALLOW_MUSIC_DUR_DISABLE,
+                to_deserialize,
+            )?,
+        })
+    }
+}
+impl TalonFXConfigType for AudioConfigs {}
+
+/// Configs that affect how software-limit switches behave.
+///
+/// Includes enabling software-limit switches and the threshold at which
+/// they are tripped.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct SoftwareLimitSwitchConfigs {
+    /// If enabled, the motor output is set to neutral if position exceeds
+    /// `forward_soft_limit_threshold` and forward output is requested.
+    pub forward_soft_limit_enable: bool,
+    /// If enabled, the motor output is set to neutral if position exceeds
+    /// `reverse_soft_limit_threshold` and reverse output is requested.
+    pub reverse_soft_limit_enable: bool,
+    /// Position threshold for forward soft limit features.
+    /// `forward_soft_limit_enable` must be enabled for this to take effect.
+    ///
+    /// Range: -3.4e38 to 3.4e38 rotations
+    pub forward_soft_limit_threshold: Rotation,
+    /// Position threshold for reverse soft limit features.
+    /// `reverse_soft_limit_enable` must be enabled for this to take effect.
+    ///
+    /// Range: -3.4e38 to 3.4e38 rotations
+    pub reverse_soft_limit_threshold: Rotation,
+}
+
+impl Default for SoftwareLimitSwitchConfigs {
+    fn default() -> Self {
+        Self {
+            forward_soft_limit_enable: false,
+            reverse_soft_limit_enable: false,
+            forward_soft_limit_threshold: Rotation::from(0.0),
+            reverse_soft_limit_threshold: Rotation::from(0.0),
+        }
+    }
+}
+
+impl std::fmt::Display for SoftwareLimitSwitchConfigs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "SoftwareLimitSwitchConfigs {{ forward_soft_limit_enable: {}, reverse_soft_limit_enable: {}, forward_soft_limit_threshold: {}, reverse_soft_limit_threshold: {} }}",
+            self.forward_soft_limit_enable,
+            self.reverse_soft_limit_enable,
+            self.forward_soft_limit_threshold,
+            self.reverse_soft_limit_threshold,
+        )
+    }
+}
+
+seal! {SoftwareLimitSwitchConfigs}
+impl ConfigProtocol for SoftwareLimitSwitchConfigs {
+    fn serialize(&self) -> Status<String> {
+        let mut ss = String::new();
+        ss.push_str(&serialize_bool(
+            SPN::CONFIG_FORWARD_SOFT_LIMIT_ENABLE,
+            self.forward_soft_limit_enable,
+        )?);
+        ss.push_str(&serialize_bool(
+            SPN::CONFIG_REVERSE_SOFT_LIMIT_ENABLE,
+            self.reverse_soft_limit_enable,
+        )?);
+        ss.push_str(&serialize_double(
+            SPN::CONFIG_FORWARD_SOFT_LIMIT_THRESHOLD,
+            self.forward_soft_limit_threshold.value(),
+        )?);
+        ss.push_str(&serialize_double(
+            SPN::CONFIG_REVERSE_SOFT_LIMIT_THRESHOLD,
+            self.reverse_soft_limit_threshold.value(),
+        )?);
+        Ok(ss)
+    }
+
+    fn deserialize(to_deserialize: &str) -> Status<Self> {
+        Ok(Self {
+            forward_soft_limit_enable: deserialize_bool(
+                SPN::CONFIG_FORWARD_SOFT_LIMIT_ENABLE,
+                to_deserialize,
+            )?,
+            reverse_soft_limit_enable: deserialize_bool(
+                SPN::CONFIG_REVERSE_SOFT_LIMIT_ENABLE,
+                to_deserialize,
+            )?,
+            forward_soft_limit_threshold: Rotation::from(deserialize_double(
+                SPN::CONFIG_FORWARD_SOFT_LIMIT_THRESHOLD,
+                to_deserialize,
+            )?),
+            reverse_soft_limit_threshold: Rotation::from(deserialize_double(
+                SPN::CONFIG_REVERSE_SOFT_LIMIT_THRESHOLD,
+                to_deserialize,
+            )?),
+        })
+    }
+}
+impl TalonFXConfigType for SoftwareLimitSwitchConfigs {}
+
+/// Configs for Motion Magic®.
+///
+/// Includes Velocity, Acceleration, Jerk, and Expo parameters.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct MotionMagicConfigs {
+    /// This is the maximum velocity Motion Magic® based control modes are
+    /// allowed to use. Motion Magic® Velocity control modes do not use this
+    /// config. When using Motion Magic® Expo control modes, setting this to 0
+    /// will allow the profile to run to the max possible velocity based on
+    /// `motion_magic_expo_kv`.
+    ///
+    /// Range: 0 to 9999 rotations per second
+    pub motion_magic_cruise_velocity: RotationPerSec,
+    /// This is the target acceleration Motion Magic® based control modes are
+    /// allowed to use. Motion Magic® Expo control modes do not use this
+    /// config.
+    ///
+    /// Range: 0 to 9999 rotations per second²
+    pub motion_magic_acceleration: RotationPerSecSqr,
+    /// This is the target jerk (acceleration derivative) Motion Magic® based
+    /// control modes are allowed to use. Motion Magic® Expo control modes do
+    /// not use this config. This allows Motion Magic® to generate S-Curve
+    /// profiles.
+    ///
+    /// The jerk is in `rotations per second³` and has a range of 0 to 9999.
+    pub motion_magic_jerk: f64,
+    /// This is the target kV used only by Motion Magic® Expo control modes,
+    /// in units of `volts per rotation per second`. Unlike the kV slot gain,
+    /// this is always in units of V/rps.
+    ///
+    /// Range: 0.001 to 100
+    pub motion_magic_expo_kv: f64,
+    /// This is the target kA used only by Motion Magic® Expo control modes,
+    /// in units of `volts per rotation per second²`. Unlike the kA slot gain,
+    /// this is always in units of V/rps².
+    ///
+    /// Range: 1e-05 to 100
+    pub motion_magic_expo_ka: f64,
+}
+
+impl Default for MotionMagicConfigs {
+    fn default() -> Self {
+        Self {
+            motion_magic_cruise_velocity: RotationPerSec::from(0.0),
+            motion_magic_acceleration: RotationPerSecSqr::from(0.0),
+            motion_magic_jerk: 0.0,
+            motion_magic_expo_kv: 0.12,
+            motion_magic_expo_ka: 0.1,
+        }
+    }
+}
+
+impl std::fmt::Display for MotionMagicConfigs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "MotionMagicConfigs {{ motion_magic_cruise_velocity: {}, motion_magic_acceleration: {}, motion_magic_jerk: {}, motion_magic_expo_kv: {}, motion_magic_expo_ka: {} }}",
+            self.motion_magic_cruise_velocity,
+            self.motion_magic_acceleration,
+            self.motion_magic_jerk,
+            self.motion_magic_expo_kv,
+            self.motion_magic_expo_ka,
+        )
+    }
+}
+
+seal! {MotionMagicConfigs}
+impl ConfigProtocol for MotionMagicConfigs {
+    fn serialize(&self) -> Status<String> {
+        let mut ss = String::new();
+        ss.push_str(&serialize_double(
+            SPN::CONFIG_MOTION_MAGIC_CRUISE_VELOCITY,
+            self.motion_magic_cruise_velocity.value(),
+        )?);
+        ss.push_str(&serialize_double(
+            SPN::CONFIG_MOTION_MAGIC_ACCELERATION,
+            self.motion_magic_acceleration.value(),
+        )?);
+        ss.push_str(&serialize_double(
+            SPN::CONFIG_MOTION_MAGIC_JERK,
+            self.motion_magic_jerk,
+        )?);
+        ss.push_str(&serialize_double(
+            SPN::CONFIG_MOTION_MAGIC_EXPO_KV,
+            self.motion_magic_expo_kv,
+        )?);
+        ss.push_str(&serialize_double(
+            SPN::CONFIG_MOTION_MAGIC_EXPO_KA,
+            self.motion_magic_expo_ka,
+        )?);
+        Ok(ss)
+    }
+
+    fn deserialize(to_deserialize: &str) -> Status<Self> {
+        Ok(Self {
+            motion_magic_cruise_velocity: RotationPerSec::from(deserialize_double(
+                SPN::CONFIG_MOTION_MAGIC_CRUISE_VELOCITY,
+                to_deserialize,
+            )?),
+            motion_magic_acceleration: RotationPerSecSqr::from(deserialize_double(
+                SPN::CONFIG_MOTION_MAGIC_ACCELERATION,
+                to_deserialize,
+            )?),
+            motion_magic_jerk: deserialize_double(SPN::CONFIG_MOTION_MAGIC_JERK, to_deserialize)?,
+            motion_magic_expo_kv: deserialize_double(
+                SPN::CONFIG_MOTION_MAGIC_EXPO_KV,
+                to_deserialize,
+            )?,
+            motion_magic_expo_ka: deserialize_double(
+                SPN::CONFIG_MOTION_MAGIC_EXPO_KA,
+                to_deserialize,
+            )?,
+        })
+    }
+}
+impl TalonFXConfigType for MotionMagicConfigs {}
+
+/// Custom Params.
+///
+/// Custom paramaters that have no real impact on controller.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct CustomParamsConfigs {
+    /// Custom parameter 0. This is provided to allow end-applications to
+    /// store persistent information in the device.
+    ///
+    /// Range: -32768 to 32767
+    pub custom_param0: i32,
+    /// Custom parameter 1. This is provided to allow end-applications to
+    /// store persistent information in the device.
+    ///
+    /// Range: -32768 to 32767
+    pub custom_param1: i32,
+}
+
+impl std::fmt::Display for CustomParamsConfigs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "CustomParamsConfigs {{ custom_param0: {}, custom_param1: {} }}",
+            self.custom_param0, self.custom_param1,
+        )
+    }
+}
+
+seal! {CustomParamsConfigs}
+impl ConfigProtocol for CustomParamsConfigs {
+    fn serialize(&self) -> Status<String> {
+        let mut ss = String::new();
+        ss.push_str(&serialize_int(SPN::CUSTOM_PARAM0, self.custom_param0)?);
+        ss.push_str(&serialize_int(SPN::CUSTOM_PARAM1, self.custom_param1)?);
+        Ok(ss)
+    }
+
+    fn deserialize(to_deserialize: &str) -> Status<Self> {
+        Ok(Self {
+            custom_param0: deserialize_int(SPN::CUSTOM_PARAM0, to_deserialize)?,
+            custom_param1: deserialize_int(SPN::CUSTOM_PARAM1, to_deserialize)?,
+        })
+    }
+}
+impl TalonFXConfigType for CustomParamsConfigs {}
+
+/// Configs that affect general behavior during closed-looping.
+///
+/// Includes Continuous Wrap features.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct ClosedLoopGeneralConfigs {
+    /// Wrap position error within [-0.5,+0.5) mechanism rotations. Typically
+    /// used for continuous position closed-loops like swerve azimuth.
+    ///
+    /// This uses the mechanism rotation value. If there is a gear ratio
+    /// between the sensor and the mechanism, make sure to apply a
+    /// `sensor_to_mechanism_ratio` so the closed loop operates on the full
+    /// rotation.
+    pub continuous_wrap: bool,
+}
+
+impl std::fmt::Display for ClosedLoopGeneralConfigs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ClosedLoopGeneralConfigs {{ continuous_wrap: {} }}",
+            self.continuous_wrap,
+        )
+    }
+}
+
+seal! {ClosedLoopGeneralConfigs}
+impl ConfigProtocol for ClosedLoopGeneralConfigs {
+    fn serialize(&self) -> Status<String> {
+        let mut ss = String::new();
+        ss.push_str(&serialize_bool(
+            SPN::CONFIG_CONTINUOUS_WRAP,
+            self.continuous_wrap,
+        )?);
+        Ok(ss)
+    }
+
+    fn deserialize(to_deserialize: &str) -> Status<Self> {
+        Ok(Self {
+            continuous_wrap: deserialize_bool(SPN::CONFIG_CONTINUOUS_WRAP, to_deserialize)?,
+        })
+    }
+}
+impl TalonFXConfigType for ClosedLoopGeneralConfigs {}
+
+/// Gains for the specified slot.
+///
+/// If this slot is selected, these gains are used in closed loop control
+/// requests.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Slot0Configs {
+    /// Proportional Gain.
+    ///
+    /// The units for this gain is dependent on the control mode. Since this
+    /// gain is multiplied by error in the input, the units should be defined
+    /// as units of output per unit of input error. For example, when
+    /// controlling velocity using a duty cycle closed loop, the units for the
+    /// proportional gain will be duty cycle per rps of error, or 1/rps.
+    ///
+    /// Range: 0 to 3.4e38
+    pub kp: f64,
+    /// Integral Gain.
+    ///
+    /// The units for this gain is dependent on the control mode. Since this
+    /// gain is multiplied by error in the input integrated over time (in
+    /// units of seconds), the units should be defined as units of output per
+    /// unit of integrated input error.
+    ///
+    /// Range: 0 to 3.4e38
+    pub ki: f64,
+    /// Derivative Gain.
+    ///
+    /// The units for this gain is dependent on the control mode. Since this
+    /// gain is multiplied by the derivative of error in the input with respect
+    /// to time (in units of seconds), the units should be defined as units of
+    /// output per unit of the differentiated input error.
+    ///
+    /// Range: 0 to 3.4e38
+    pub kd: f64,
+    /// Static Feedforward Gain.
+    ///
+    /// This is added to the closed loop output. The sign is determined by
+    /// target velocity. The unit for this constant is dependent on the control
+    /// mode, typically fractional duty cycle, voltage, or torque current.
+    ///
+    /// Range: -512 to 511
+    pub ks: f64,
+    /// Velocity Feedforward Gain.
+    ///
+    /// The units for this gain is dependent on the control mode. Since this
+    /// gain is multiplied by the requested velocity, the units should be
+    /// defined as units of output per unit of requested input velocity.
+    ///
+    /// Range: 0 to 3.4e38
+    pub kv: f64,
+    /// Acceleration Feedforward Gain.
+    ///
+    /// The units for this gain is dependent on the control mode. Since this
+    /// gain is multiplied by the requested acceleration, the units should be
+    /// defined as units of output per unit of requested input acceleration.
+    ///
+    /// Range: 0 to 3.4e38
+    pub ka: f64,
+    /// Gravity Feedforward/Feedback Gain.
+    ///
+    /// This is added to the closed loop output. The sign is determined by
+    /// `gravity_type`. The unit for this constant is dependent on the control
+    /// mode, typically fractional duty cycle, voltage, or torque current.
+    ///
+    /// Range: -512 to 511
+    pub kg: f64,
+    /// Gravity Feedforward/Feedback Type.
+    ///
+    /// This determines the type of the gravity feedforward/feedback. Choose
+    /// `ElevatorStatic` for systems where the gravity feedforward is constant,
+    /// such as an elevator. Choose `ArmCosine` for systems where the gravity
+    /// feedforward is dependent on the angular position of the mechanism.
+    pub gravity_type: GravityTypeValue,
+}
+
+impl Default for Slot0Configs {
+    fn default() -> Self {
+        Self {
+            kp: 0.0,
+            ki: 0.0,
+            kd: 0.0,
+            ks: 0.0,
+            kv: 0.0,
+            ka: 0.0,
+            kg: 0.0,
+            gravity_type: GravityTypeValue::ElevatorStatic,
+        }
+    }
+}
+
+impl std::fmt::Display for Slot0Configs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Slot0Configs {{ kp: {}, ki: {}, kd: {}, ks: {}, kv: {}, ka: {}, kg: {}, gravity_type: {} }}",
+            self.kp,
+            self.ki,
+            self.kd,
+            self.ks,
+            self.kv,
+            self.ka,
+            self.kg,
+            self.gravity_type,
+        )
+    }
+}
+
+seal! {Slot0Configs}
+impl ConfigProtocol for Slot0Configs {
+    fn serialize(&self) -> Status<String> {
+        let mut ss = String::new();
+        ss.push_str(&serialize_double(SPN::SLOT0_KP, self.kp)?);
+        ss.push_str(&serialize_double(SPN::SLOT0_KI, self.ki)?);
+        ss.push_str(&serialize_double(SPN::SLOT0_KD, self.kd)?);
+        ss.push_str(&serialize_double(SPN::SLOT0_KS, self.ks)?);
+        ss.push_str(&serialize_double(SPN::SLOT0_KV, self.kv)?);
+        ss.push_str(&serialize_double(SPN::SLOT0_KA, self.ka)?);
+        ss.push_str(&serialize_double(SPN::SLOT0_KG, self.kg)?);
+        ss.push_str(&serialize_int(
+            SPN::SLOT0_KG_TYPE,
+            self.gravity_type.into(),
+        )?);
+        Ok(ss)
+    }
+
+    fn deserialize(to_deserialize: &str) -> Status<Self> {
+        Ok(Self {
+            kp: deserialize_double(SPN::SLOT0_KP, to_deserialize)?,
+            ki: deserialize_double(SPN::SLOT0_KI, to_deserialize)?,
+            kd: deserialize_double(SPN::SLOT0_KD, to_deserialize)?,
+            ks: deserialize_double(SPN::SLOT0_KS, to_deserialize)?,
+            kv: deserialize_double(SPN::SLOT0_KV, to_deserialize)?,
+            ka: deserialize_double(SPN::SLOT0_KA, to_deserialize)?,
+            kg: deserialize_double(SPN::SLOT0_KG, to_deserialize)?,
+            gravity_type: deserialize_int(SPN::SLOT0_KG_TYPE, to_deserialize)?
+                .try_into()
+                .map_err(|_| StatusCode::CouldNotDeserializeString)?,
+        })
+    }
+}
+impl TalonFXConfigType for Slot0Configs {}
+
+/// Gains for the specified slot.
+///
+/// If this slot is selected, these gains are used in closed loop control
+/// requests.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Slot1Configs {
+    /// Proportional Gain.
+    ///
+    /// The units for this gain is dependent on the control mode. Since this
+    /// gain is multiplied by error in the input, the units should be defined
+    /// as units of output per unit of input error. For example, when
+    /// controlling velocity using a duty cycle closed loop, the units for the
+    /// proportional gain will be duty cycle per rps of error, or 1/rps.
+    ///
+    /// Range: 0 to 3.4e38
+    pub kp: f64,
+    /// Integral Gain.
+    ///
+    /// The units for this gain is dependent on the control mode. Since this
+    /// gain is multiplied by error in the input integrated over time (in
+    /// units of seconds), the units should be defined as units of output per
+    /// unit of integrated input error.
+    ///
+    /// Range: 0 to 3.4e38
+    pub ki: f64,
+    /// Derivative Gain.
+    ///
+    /// The units for this gain is dependent on the control mode. Since this
+    /// gain is multiplied by the derivative of error in the input with respect
+    /// to time (in units of seconds), the units should be defined as units of
+    /// output per unit of the differentiated input error.
+    ///
+    /// Range: 0 to 3.4e38
+    pub kd: f64,
+    /// Static Feedforward Gain.
+    ///
+    /// This is added to the closed loop output. The sign is determined by
+    /// target velocity. The unit for this constant is dependent on the control
+    /// mode, typically fractional duty cycle, voltage, or torque current.
+    ///
+    /// Range: -512 to 511
+    pub ks: f64,
+    /// Velocity Feedforward Gain.
+    ///
+    /// The units for this gain is dependent on the control mode. Since this
+    /// gain is multiplied by the requested velocity, the units should be
+    /// defined as units of output per unit of requested input velocity.
+    ///
+    /// Range: 0 to 3.4e38
+    pub kv: f64,
+    /// Acceleration Feedforward Gain.
+    ///
+    /// The units for this gain is dependent on the control mode. Since this
+    /// gain is multiplied by the requested acceleration, the units should be
+    /// defined as units of output per unit of requested input acceleration.
+    ///
+    /// Range: 0 to 3.4e38
+    pub ka: f64,
+    /// Gravity Feedforward/Feedback Gain.
+    ///
+    /// This is added to the closed loop output. The sign is determined by
+    /// `gravity_type`. The unit for this constant is dependent on the control
+    /// mode, typically fractional duty cycle, voltage, or torque current.
+    ///
+    /// Range: -512 to 511
+    pub kg: f64,
+    /// Gravity Feedforward/Feedback Type.
+    ///
+    /// This determines the type of the gravity feedforward/feedback. Choose
+    /// `ElevatorStatic` for systems where the gravity feedforward is constant,
+    /// such as an elevator. Choose `ArmCosine` for systems where the gravity
+    /// feedforward is dependent on the angular position of the mechanism.
+    pub gravity_type: GravityTypeValue,
+}
+
+impl Default for Slot1Configs {
+    fn default() -> Self {
+        Self {
+            kp: 0.0,
+            ki: 0.0,
+            kd: 0.0,
+            ks: 0.0,
+            kv: 0.0,
+            ka: 0.0,
+            kg: 0.0,
+            gravity_type: GravityTypeValue::ElevatorStatic,
+        }
+    }
+}
+
+impl std::fmt::Display for Slot1Configs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Slot1Configs {{ kp: {}, ki: {}, kd: {}, ks: {}, kv: {}, ka: {}, kg: {}, gravity_type: {} }}",
+            self.kp,
+            self.ki,
+            self.kd,
+            self.ks,
+            self.kv,
+            self.ka,
+            self.kg,
+            self.gravity_type,
+        )
+    }
+}
+
+seal! {Slot1Configs}
+impl ConfigProtocol for Slot1Configs {
+    fn serialize(&self) -> Status<String> {
+        let mut ss = String::new();
+        ss.push_str(&serialize_double(SPN::SLOT1_KP, self.kp)?);
+        ss.push_str(&serialize_double(SPN::SLOT1_KI, self.ki)?);
+        ss.push_str(&serialize_double(SPN::SLOT1_KD, self.kd)?);
+        ss.push_str(&serialize_double(SPN::SLOT1_KS, self.ks)?);
+        ss.push_str(&serialize_double(SPN::SLOT1_KV, self.kv)?);
+        ss.push_str(&serialize_double(SPN::SLOT1_KA, self.ka)?);
+        ss.push_str(&serialize_double(SPN::SLOT1_KG, self.kg)?);
+        ss.push_str(&serialize_int(
+            SPN::SLOT1_KG_TYPE,
+            self.gravity_type.into(),
+        )?);
+        Ok(ss)
+    }
+
+    fn deserialize(to_deserialize: &str) -> Status<Self> {
+        Ok(Self {
+            kp: deserialize_double(SPN::SLOT1_KP, to_deserialize)?,
+            ki: deserialize_double(SPN::SLOT1_KI, to_deserialize)?,
+            kd: deserialize_double(SPN::SLOT1_KD, to_deserialize)?,
+            ks: deserialize_double(SPN::SLOT1_KS, to_deserialize)?,
+            kv: deserialize_double(SPN::SLOT1_KV, to_deserialize)?,
+            ka: deserialize_double(SPN::SLOT1_KA, to_deserialize)?,
+            kg: deserialize_double(SPN::SLOT1_KG, to_deserialize)?,
+            gravity_type: deserialize_int(SPN::SLOT1_KG_TYPE, to_deserialize)?
+                .try_into()
+                .map_err(|_| StatusCode::CouldNotDeserializeString)?,
+        })
+    }
+}
+impl TalonFXConfigType for Slot1Configs {}
+
+/// Gains for the specified slot.
+///
+/// If this slot is selected, these gains are used in closed loop control
+/// requests.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Slot2Configs {
+    /// Proportional Gain.
+    ///
+    /// The units for this gain is dependent on the control mode. Since this
+    /// gain is multiplied by error in the input, the units should be defined
+    /// as units of output per unit of input error. For example, when
+    /// controlling velocity using a duty cycle closed loop, the units for the
+    /// proportional gain will be duty cycle per rps of error, or 1/rps.
+    ///
+    /// Range: 0 to 3.4e38
+    pub kp: f64,
+    /// Integral Gain.
+    ///
+    /// The units for this gain is dependent on the control mode. Since this
+    /// gain is multiplied by error in the input integrated over time (in
+    /// units of seconds), the units should be defined as units of output per
+    /// unit of integrated input error.
+    ///
+    /// Range: 0 to 3.4e38
+    pub ki: f64,
+    /// Derivative Gain.
+    ///
+    /// The units for this gain is dependent on the control mode. Since this
+    /// gain is multiplied by the derivative of error in the input with respect
+    /// to time (in units of seconds), the units should be defined as units of
+    /// output per unit of the differentiated input error.
+    ///
+    /// Range: 0 to 3.4e38
+    pub kd: f64,
+    /// Static Feedforward Gain.
+    ///
+    /// This is added to the closed loop output. The sign is determined by
+    /// target velocity. The unit for this constant is dependent on the control
+    /// mode, typically fractional duty cycle, voltage, or torque current.
+    ///
+    /// Range: -512 to 511
+    pub ks: f64,
+    /// Velocity Feedforward Gain.
+    ///
+    /// The units for this gain is dependent on the control mode. Since this
+    /// gain is multiplied by the requested velocity, the units should be
+    /// defined as units of output per unit of requested input velocity.
+    ///
+    /// Range: 0 to 3.4e38
+    pub kv: f64,
+    /// Acceleration Feedforward Gain.
+    ///
+    /// The units for this gain is dependent on the control mode. Since this
+    /// gain is multiplied by the requested acceleration, the units should be
+    /// defined as units of output per unit of requested input acceleration.
+    ///
+    /// Range: 0 to 3.4e38
+    pub ka: f64,
+    /// Gravity Feedforward/Feedback Gain.
+    ///
+    /// This is added to the closed loop output. The sign is determined by
+    /// `gravity_type`. The unit for this constant is dependent on the control
+    /// mode, typically fractional duty cycle, voltage, or torque current.
+    ///
+    /// Range: -512 to 511
+    pub kg: f64,
+    /// Gravity Feedforward/Feedback Type.
+    ///
+    /// This determines the type of the gravity feedforward/feedback. Choose
+    /// `ElevatorStatic` for systems where the gravity feedforward is constant,
+    /// such as an elevator. Choose `ArmCosine` for systems where the gravity
+    /// feedforward is dependent on the angular position of the mechanism.
+    pub gravity_type: GravityTypeValue,
+}
+
+impl Default for Slot2Configs {
+    fn default() -> Self {
+        Self {
+            kp: 0.0,
+            ki: 0.0,
+            kd: 0.0,
+            ks: 0.0,
+            kv: 0.0,
+            ka: 0.0,
+            kg: 0.0,
+            gravity_type: GravityTypeValue::ElevatorStatic,
+        }
+    }
+}
+
+impl std::fmt::Display for Slot2Configs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Slot2Configs {{ kp: {}, ki: {}, kd: {}, ks: {}, kv: {}, ka: {}, kg: {}, gravity_type: {} }}",
+            self.kp,
+            self.ki,
+            self.kd,
+            self.ks,
+            self.kv,
+            self.ka,
+            self.kg,
+            self.gravity_type,
+        )
+    }
+}
+
+seal! {Slot2Configs}
+impl ConfigProtocol for Slot2Configs {
+    fn serialize(&self) -> Status<String> {
+        let mut ss = String::new();
+        ss.push_str(&serialize_double(SPN::SLOT2_KP, self.kp)?);
+        ss.push_str(&serialize_double(SPN::SLOT2_KI, self.ki)?);
+        ss.push_str(&serialize_double(SPN::SLOT2_KD, self.kd)?);
+        ss.push_str(&serialize_double(SPN::SLOT2_KS, self.ks)?);
+        ss.push_str(&serialize_double(SPN::SLOT2_KV, self.kv)?);
+        ss.push_str(&serialize_double(SPN::SLOT2_KA, self.ka)?);
+        ss.push_str(&serialize_double(SPN::SLOT2_KG, self.kg)?);
+        ss.push_str(&serialize_int(
+            SPN::SLOT2_KG_TYPE,
+            self.gravity_type.into(),
+        )?);
+        Ok(ss)
+    }
+
+    fn deserialize(to_deserialize: &str) -> Status<Self> {
+        Ok(Self {
+            kp: deserialize_double(SPN::SLOT2_KP, to_deserialize)?,
+            ki: deserialize_double(SPN::SLOT2_KI, to_deserialize)?,
+            kd: deserialize_double(SPN::SLOT2_KD, to_deserialize)?,
+            ks: deserialize_double(SPN::SLOT2_KS, to_deserialize)?,
+            kv: deserialize_double(SPN::SLOT2_KV, to_deserialize)?,
+            ka: deserialize_double(SPN::SLOT2_KA, to_deserialize)?,
+            kg: deserialize_double(SPN::SLOT2_KG, to_deserialize)?,
+            gravity_type: deserialize_int(SPN::SLOT2_KG_TYPE, to_deserialize)?
+                .try_into()
+                .map_err(|_| StatusCode::CouldNotDeserializeString)?,
+        })
+    }
+}
+impl TalonFXConfigType for Slot2Configs {}
+
+/// Invert state of the device.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Default, num_enum::IntoPrimitive, num_enum::TryFromPrimitive,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(i32)]
+pub enum InvertedValue {
+    #[default]
+    CounterClockwisePositive = 0,
+    ClockwisePositive = 1,
+}
+impl std::fmt::Display for InvertedValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvertedValue::CounterClockwisePositive => write!(f, "CounterClockwisePositive"),
+            InvertedValue::ClockwisePositive => write!(f, "ClockwisePositive"),
+        }
+    }
+}
+
+/// The state of the motor controller bridge when output is neutral or disabled.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Default, num_enum::IntoPrimitive, num_enum::TryFromPrimitive,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(i32)]
+pub enum NeutralModeValue {
+    #[default]
+    Coast = 0,
+    Brake = 1,
+}
+impl std::fmt::Display for NeutralModeValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NeutralModeValue::Coast => write!(f, "Coast"),
+            NeutralModeValue::Brake => write!(f, "Brake"),
+        }
+    }
+}
+
+/// Choose what sensor source is reported via API and used by closed-loop and limit features.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Default, num_enum::IntoPrimitive, num_enum::TryFromPrimitive,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(i32)]
+pub enum FeedbackSensorSourceValue {
+    #[default]
+    RotorSensor = 0,
+    RemoteCANcoder = 1,
+    RemotePigeon2Yaw = 2,
+    RemotePigeon2Pitch = 3,
+    RemotePigeon2Roll = 4,
+    FusedCANcoder = 5,
+    SyncCANcoder = 6,
+}
+impl std::fmt::Display for FeedbackSensorSourceValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FeedbackSensorSourceValue::RotorSensor => write!(f, "RotorSensor"),
+            FeedbackSensorSourceValue::RemoteCANcoder => write!(f, "RemoteCANcoder"),
+            FeedbackSensorSourceValue::RemotePigeon2Yaw => write!(f, "RemotePigeon2Yaw"),
+            FeedbackSensorSourceValue::RemotePigeon2Pitch => write!(f, "RemotePigeon2Pitch"),
+            FeedbackSensorSourceValue::RemotePigeon2Roll => write!(f, "RemotePigeon2Roll"),
+            FeedbackSensorSourceValue::FusedCANcoder => write!(f, "FusedCANcoder"),
+            FeedbackSensorSourceValue::SyncCANcoder => write!(f, "SyncCANcoder"),
+        }
+    }
+}
+
+/// Determines if the forward limit switch is normally-open (default) or normally-closed.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Default, num_enum::IntoPrimitive, num_enum::TryFromPrimitive,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(i32)]
+pub enum ForwardLimitTypeValue {
+    #[default]
+    NormallyOpen = 0,
+    NormallyClosed = 1,
+}
+impl std::fmt::Display for ForwardLimitTypeValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ForwardLimitTypeValue::NormallyOpen => write!(f, "NormallyOpen"),
+            ForwardLimitTypeValue::NormallyClosed => write!(f, "NormallyClosed"),
+        }
+    }
+}
+
+/// Determines where to poll the forward limit switch.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Default, num_enum::IntoPrimitive, num_enum::TryFromPrimitive,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(i32)]
+pub enum ForwardLimitSourceValue {
+    #[default]
+    LimitSwitchPin = 0,
+    RemoteTalonFX = 1,
+    RemoteCANifier = 2,
+    RemoteCANcoder = 4,
+    Disabled = 3,
+}
+impl std::fmt::Display for ForwardLimitSourceValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ForwardLimitSourceValue::LimitSwitchPin => write!(f, "LimitSwitchPin"),
+            ForwardLimitSourceValue::RemoteTalonFX => write!(f, "RemoteTalonFX"),
+            ForwardLimitSourceValue::RemoteCANifier => write!(f, "RemoteCANifier"),
+            ForwardLimitSourceValue::RemoteCANcoder => write!(f, "RemoteCANcoder"),
+            ForwardLimitSourceValue::Disabled => write!(f, "Disabled"),
+        }
+    }
+}
+
+/// Determines if the reverse limit switch is normally-open (default) or normally-closed.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Default, num_enum::IntoPrimitive, num_enum::TryFromPrimitive,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(i32)]
+pub enum ReverseLimitTypeValue {
+    #[default]
+    NormallyOpen = 0,
+    NormallyClosed = 1,
+}
+impl std::fmt::Display for ReverseLimitTypeValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReverseLimitTypeValue::NormallyOpen => write!(f, "NormallyOpen"),
+            ReverseLimitTypeValue::NormallyClosed => write!(f, "NormallyClosed"),
+        }
+    }
+}
+
+/// Determines where to poll the reverse limit switch.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Default, num_enum::IntoPrimitive, num_enum::TryFromPrimitive,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(i32)]
+pub enum ReverseLimitSourceValue {
+    #[default]
+    LimitSwitchPin = 0,
+    RemoteTalonFX = 1,
+    RemoteCANifier = 2,
+    RemoteCANcoder = 4,
+    Disabled = 3,
+}
+impl std::fmt::Display for ReverseLimitSourceValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReverseLimitSourceValue::LimitSwitchPin => write!(f, "LimitSwitchPin"),
+            ReverseLimitSourceValue::RemoteTalonFX => write!(f, "RemoteTalonFX"),
+            ReverseLimitSourceValue::RemoteCANifier => write!(f, "RemoteCANifier"),
+            ReverseLimitSourceValue::RemoteCANcoder => write!(f, "RemoteCANcoder"),
+            ReverseLimitSourceValue::Disabled => write!(f, "Disabled"),
+        }
+    }
+}
+
+/// Choose what sensor source is used for differential control of a mechanism.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Default, num_enum::IntoPrimitive, num_enum::TryFromPrimitive,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(i32)]
+pub enum DifferentialSensorSourceValue {
+    #[default]
+    Disabled = 0,
+    RemoteTalonFXDiff = 1,
+    RemotePigeon2Yaw = 2,
+    RemotePigeon2Pitch = 3,
+    RemotePigeon2Roll = 4,
+    RemoteCANcoder = 5,
+}
+impl std::fmt::Display for DifferentialSensorSourceValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DifferentialSensorSourceValue::Disabled => write!(f, "Disabled"),
+            DifferentialSensorSourceValue::RemoteTalonFXDiff => write!(f, "RemoteTalonFXDiff"),
+            DifferentialSensorSourceValue::RemotePigeon2Yaw => write!(f, "RemotePigeon2Yaw"),
+            DifferentialSensorSourceValue::RemotePigeon2Pitch => write!(f, "RemotePigeon2Pitch"),
+            DifferentialSensorSourceValue::RemotePigeon2Roll => write!(f, "RemotePigeon2Roll"),
+            DifferentialSensorSourceValue::RemoteCANcoder => write!(f, "RemoteCANcoder"),
+        }
+    }
+}
+
+/// Gravity feedforward type, determines how `kG` is applied.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Default, num_enum::IntoPrimitive, num_enum::TryFromPrimitive,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(i32)]
+pub enum GravityTypeValue {
+    #[default]
+    ElevatorStatic = 0,
+    ArmCosine = 1,
+}
+impl std::fmt::Display for GravityTypeValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GravityTypeValue::ElevatorStatic => write!(f, "ElevatorStatic"),
+            GravityTypeValue::ArmCosine => write!(f, "ArmCosine"),
+        }
+    }
+}
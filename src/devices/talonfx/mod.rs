@@ -0,0 +1,230 @@
+pub mod config;
+pub mod signals;
+
+use frclib_core::units::{
+    angle::Rotation,
+    time::{Second, Time},
+};
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+use crate::{controls::ControlRequest, signals::faults::Fault, spn::SPN, Status};
+
+use self::{
+    config::{TalonFXConfigType, TalonFXConfiguration},
+    signals::TalonFXCache,
+};
+
+use super::{
+    close_device,
+    config_dump::ConfigDump,
+    config_native,
+    licensing::{self, LicenseStatus},
+    propose_device, version, DeviceCanID, DeviceIdentifier, ParentDevice,
+};
+
+//do not implement clone for this
+#[derive(Debug)]
+pub struct TalonFX {
+    identifier: DeviceIdentifier,
+    cache: Arc<RwLock<TalonFXCache>>,
+}
+impl TalonFX {
+    pub fn new(can_id: impl Into<DeviceCanID>, can_bus: String) -> Status<Self> {
+        let identifier =
+            DeviceIdentifier::try_new(can_id.into(), super::Model::talon_fx(), can_bus)?;
+        propose_device(identifier.clone())?;
+        let inner = Arc::new(RwLock::new(Default::default()));
+        Ok(Self {
+            identifier,
+            cache: inner,
+        })
+    }
+
+    //mutable so it holds a unique reference to the device
+    pub fn configurator(&mut self) -> TalonFXConfigurator {
+        TalonFXConfigurator {
+            identifier: &mut self.identifier,
+        }
+    }
+
+    /// Sends a control request to the motor controller,
+    /// any control request previously sent to this device will be cancelled.
+    ///
+    /// All control requests in [`crate::controls`] can be passed to this function.
+    pub fn set_control(&self, request: impl Into<ControlRequest>) -> Status<()> {
+        request.into().send(self.identifier.clone(), true)
+    }
+
+    /// Sets the mechanism position of the device, the write is confirmed by the device
+    /// before this returns.
+    ///
+    /// Reading the cached `position` signal fails with
+    /// [`StatusCode::SigNotUpdated`](crate::error::StatusCode::SigNotUpdated) until it is refreshed.
+    pub fn set_position(&self, position: Rotation) -> Status<()> {
+        self.set_position_timeout(position, Second::new(crate::DEFAULT_TIMEOUT))
+    }
+
+    /// Sets the mechanism position of the device, the write is confirmed by the device
+    /// before this returns.
+    pub fn set_position_timeout(&self, position: Rotation, timeout: impl Time) -> Status<()> {
+        config_native::send_action(
+            &self.identifier,
+            SPN::TALON_FX_SET_SENSOR_POSITION,
+            position.value(),
+            timeout.to_seconds().value(),
+        )?;
+        //the cached position is stale now
+        self.cache.write().invalidate_position();
+        Ok(())
+    }
+
+    /// Checks that the firmware on the device is compatible with the phoenix library
+    /// this crate is running against, see [`ParentDevice::check_compatibility`].
+    ///
+    /// A mismatch is reported as [`StatusCode::FirmwareTooOld`](crate::error::StatusCode::FirmwareTooOld),
+    /// [`StatusCode::FirmwareTooNew`](crate::error::StatusCode::FirmwareTooNew)
+    /// or [`StatusCode::ApiTooOld`](crate::error::StatusCode::ApiTooOld).
+    pub fn check_compatibility_timeout(&self, timeout: impl Time) -> Status<()> {
+        version::check_compatibility(&self.identifier, timeout.to_seconds().value())
+    }
+
+    /// Reads the Pro and Season Pass licensing of the device
+    /// and whether it is using a feature it is not licensed for
+    pub fn license_status(&self) -> Status<LicenseStatus> {
+        licensing::license_status(&self.identifier, crate::DEFAULT_TIMEOUT)
+    }
+
+    /// Reads the Pro and Season Pass licensing of the device
+    /// and whether it is using a feature it is not licensed for
+    pub fn license_status_timeout(&self, timeout: impl Time) -> Status<LicenseStatus> {
+        licensing::license_status(&self.identifier, timeout.to_seconds().value())
+    }
+
+    /// Clears all sticky faults on the device, see [`ParentDevice::clear_sticky_faults`]
+    pub fn clear_sticky_faults_timeout(&self, timeout: impl Time) -> Status<()> {
+        config_native::clear_sticky_faults(&self.identifier, timeout.to_seconds().value())
+    }
+
+    /// Clears a single sticky fault on the device.
+    ///
+    /// Returns [`StatusCode::InvalidModel`](crate::error::StatusCode::InvalidModel)
+    /// if this device can not report `fault`.
+    pub fn clear_sticky_fault(&self, fault: Fault) -> Status<()> {
+        config_native::clear_sticky_fault(&self.identifier, fault, crate::DEFAULT_TIMEOUT)
+    }
+
+    /// Clears a single sticky fault on the device.
+    ///
+    /// Returns [`StatusCode::InvalidModel`](crate::error::StatusCode::InvalidModel)
+    /// if this device can not report `fault`.
+    pub fn clear_sticky_fault_timeout(&self, fault: Fault, timeout: impl Time) -> Status<()> {
+        config_native::clear_sticky_fault(&self.identifier, fault, timeout.to_seconds().value())
+    }
+}
+
+impl Drop for TalonFX {
+    fn drop(&mut self) {
+        close_device(&self.identifier);
+    }
+}
+
+impl ParentDevice for TalonFX {
+    fn identifier(&self) -> &DeviceIdentifier {
+        &self.identifier
+    }
+}
+
+pub struct TalonFXConfigurator<'hw> {
+    //mutable so it holds a unique reference to the device
+    identifier: &'hw mut DeviceIdentifier,
+}
+impl TalonFXConfigurator<'_> {
+    pub fn apply_config(&mut self, config: impl TalonFXConfigType) -> Status<()> {
+        let fpc = config.future_proof_configs();
+        config_native::set_config(
+            self.identifier.clone(),
+            config,
+            crate::DEFAULT_TIMEOUT,
+            fpc,
+            true,
+        )
+    }
+    pub fn apply_config_timeout(
+        &mut self,
+        config: impl TalonFXConfigType,
+        timeout: impl Time,
+    ) -> Status<()> {
+        let fpc = config.future_proof_configs();
+        config_native::set_config(
+            self.identifier.clone(),
+            config,
+            timeout.to_seconds().value(),
+            fpc,
+            true,
+        )
+    }
+    /// Applies the config without blocking the async executor
+    #[cfg(feature = "async")]
+    pub async fn apply_config_async(
+        &mut self,
+        config: impl TalonFXConfigType + Send + 'static,
+    ) -> Status<()> {
+        self.apply_config_timeout_async(
+            config,
+            frclib_core::units::time::Second::new(crate::DEFAULT_TIMEOUT),
+        )
+        .await
+    }
+    /// Applies the config without blocking the async executor
+    #[cfg(feature = "async")]
+    pub async fn apply_config_timeout_async(
+        &mut self,
+        config: impl TalonFXConfigType + Send + 'static,
+        timeout: impl Time,
+    ) -> Status<()> {
+        let fpc = config.future_proof_configs();
+        let identifier = self.identifier.clone();
+        let timeout = timeout.to_seconds().value();
+        crate::blocking::run(move || {
+            config_native::set_config(identifier, config, timeout, fpc, true)
+        })
+        .await
+    }
+    pub fn get_config<T: TalonFXConfigType>(&self) -> Status<T> {
+        config_native::get_config(self.identifier.clone(), crate::DEFAULT_TIMEOUT)
+    }
+    pub fn get_config_timeout<T: TalonFXConfigType>(&self, timeout: impl Time) -> Status<T> {
+        config_native::get_config(self.identifier.clone(), timeout.to_seconds().value())
+    }
+    /// Resets every config on the device to its factory default,
+    /// including configs this version of the crate does not know about.
+    pub fn factory_default(&mut self) -> Status<()> {
+        config_native::set_config(
+            self.identifier.clone(),
+            TalonFXConfiguration::default(),
+            crate::DEFAULT_TIMEOUT,
+            true,
+            true,
+        )
+    }
+    /// Resets every config on the device to its factory default,
+    /// including configs this version of the crate does not know about.
+    pub fn factory_default_timeout(&mut self, timeout: impl Time) -> Status<()> {
+        config_native::set_config(
+            self.identifier.clone(),
+            TalonFXConfiguration::default(),
+            timeout.to_seconds().value(),
+            true,
+            true,
+        )
+    }
+    /// Reads back every config value the device reports
+    pub fn refresh_all(&self) -> Status<ConfigDump> {
+        config_native::get_config(self.identifier.clone(), crate::DEFAULT_TIMEOUT)
+    }
+    /// Reads back every config value the device reports
+    pub fn refresh_all_timeout(&self, timeout: impl Time) -> Status<ConfigDump> {
+        config_native::get_config(self.identifier.clone(), timeout.to_seconds().value())
+    }
+}
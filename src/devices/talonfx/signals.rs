@@ -0,0 +1,822 @@
+use std::sync::{Arc, Weak as Aweak};
+
+use frclib_core::units::{
+    angle::Rotation,
+    angular_acceleration::RotationPerSecSqr,
+    angular_velocity::RotationPerSec,
+    energy::{Amp, Volt},
+    temperature::Celsius,
+};
+use parking_lot::RwLock;
+
+use crate::{
+    cold_signal,
+    devices::{version::FirmwareVersion, DeviceIdentifier},
+    error::StatusCode,
+    signal, signal_setup,
+    signals::{
+        faults::{FaultSet, StickyFaultSet},
+        native,
+        types::{
+            AppliedRotorPolarityValue, BridgeOutputValue, ControlModeValue, DeviceEnableValue,
+            DifferentialControlModeValue, ForwardLimitValue, MotionMagicIsRunningValue,
+            ReverseLimitValue, RobotEnableValue, SystemStateValue,
+        },
+        BaseSignal, CachedValue, RefreshableStatusSignal, SPNValue, SignalValue, SignalValueRaw,
+    },
+    spn::SPN,
+    Status,
+};
+
+use super::TalonFX;
+
+#[derive(Debug, Default)]
+pub(super) struct TalonFXCache {
+    motor_voltage: CachedValue,
+    forward_limit: CachedValue,
+    reverse_limit: CachedValue,
+    applied_rotor_polarity: CachedValue,
+    duty_cycle: CachedValue,
+    torque_current: CachedValue,
+    stator_current: CachedValue,
+    supply_current: CachedValue,
+    supply_voltage: CachedValue,
+    device_temp: CachedValue,
+    processor_temp: CachedValue,
+    rotor_velocity: CachedValue,
+    rotor_position: CachedValue,
+    velocity: CachedValue,
+    position: CachedValue,
+    acceleration: CachedValue,
+    control_mode: CachedValue,
+    motion_magic_is_running: CachedValue,
+    device_enable: CachedValue,
+    bridge_output: CachedValue,
+    closed_loop_slot: CachedValue,
+    closed_loop_reference_position: CachedValue,
+    closed_loop_reference_velocity: CachedValue,
+    closed_loop_reference_slope_position: CachedValue,
+    closed_loop_reference_slope_velocity: CachedValue,
+    closed_loop_error_position: CachedValue,
+    closed_loop_error_velocity: CachedValue,
+    closed_loop_proportional_output_duty_cycle: CachedValue,
+    closed_loop_proportional_output_voltage: CachedValue,
+    closed_loop_proportional_output_torque_current: CachedValue,
+    closed_loop_derivative_output_duty_cycle: CachedValue,
+    closed_loop_derivative_output_voltage: CachedValue,
+    closed_loop_derivative_output_torque_current: CachedValue,
+    closed_loop_output_duty_cycle: CachedValue,
+    closed_loop_output_voltage: CachedValue,
+    closed_loop_output_torque_current: CachedValue,
+    closed_loop_integrated_output_duty_cycle: CachedValue,
+    closed_loop_integrated_output_voltage: CachedValue,
+    closed_loop_integrated_output_torque_current: CachedValue,
+    closed_loop_feed_forward_duty_cycle: CachedValue,
+    closed_loop_feed_forward_voltage: CachedValue,
+    closed_loop_feed_forward_torque_current: CachedValue,
+    differential_control_mode: CachedValue,
+    differential_duty_cycle: CachedValue,
+    differential_torque_current: CachedValue,
+    differential_average_velocity: CachedValue,
+    differential_average_position: CachedValue,
+    differential_difference_velocity: CachedValue,
+    differential_difference_position: CachedValue,
+    differential_closed_loop_slot: CachedValue,
+    differential_closed_loop_reference_position: CachedValue,
+    differential_closed_loop_reference_velocity: CachedValue,
+    differential_closed_loop_reference_slope_position: CachedValue,
+    differential_closed_loop_reference_slope_velocity: CachedValue,
+    differential_closed_loop_error_position: CachedValue,
+    differential_closed_loop_error_velocity: CachedValue,
+    differential_closed_loop_proportional_output_duty_cycle: CachedValue,
+    differential_closed_loop_proportional_output_voltage: CachedValue,
+    differential_closed_loop_proportional_output_torque_current: CachedValue,
+    differential_closed_loop_derivative_output_duty_cycle: CachedValue,
+    differential_closed_loop_derivative_output_voltage: CachedValue,
+    differential_closed_loop_derivative_output_torque_current: CachedValue,
+    differential_closed_loop_output_duty_cycle: CachedValue,
+    differential_closed_loop_output_voltage: CachedValue,
+    differential_closed_loop_output_torque_current: CachedValue,
+    differential_closed_loop_integrated_output_duty_cycle: CachedValue,
+    differential_closed_loop_integrated_output_voltage: CachedValue,
+    differential_closed_loop_integrated_output_torque_current: CachedValue,
+    differential_closed_loop_feed_forward_duty_cycle: CachedValue,
+    differential_closed_loop_feed_forward_voltage: CachedValue,
+    differential_closed_loop_feed_forward_torque_current: CachedValue,
+}
+impl TalonFXCache {
+    pub(super) fn invalidate_position(&mut self) {
+        // reads fail until a refresh returns a sample from after the write
+        self.position.invalidate();
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(i32)]
+pub enum TalonFXSignalField {
+    MotorVoltage = SPN::PRO_MOTOR_OUTPUT_MOTOR_VOLTAGE as i32,
+    ForwardLimit = SPN::FORWARD_LIMIT as i32,
+    ReverseLimit = SPN::REVERSE_LIMIT as i32,
+    AppliedRotorPolarity = SPN::PRO_MOTOR_OUTPUT_ROTOR_POLARITY as i32,
+    DutyCycle = SPN::PRO_MOTOR_OUTPUT_DUTY_CYCLE as i32,
+    TorqueCurrent = SPN::PRO_MOTOR_OUTPUT_TORQUE_CURRENT as i32,
+    StatorCurrent = SPN::PRO_SUPPLY_AND_TEMP_STATOR_CURRENT as i32,
+    SupplyCurrent = SPN::PRO_SUPPLY_AND_TEMP_SUPPLY_CURRENT as i32,
+    SupplyVoltage = SPN::PRO_SUPPLY_AND_TEMP_SUPPLY_VOLTAGE as i32,
+    DeviceTemp = SPN::PRO_SUPPLY_AND_TEMP_DEVICE_TEMP as i32,
+    ProcessorTemp = SPN::PRO_SUPPLY_AND_TEMP_PROCESSOR_TEMP as i32,
+    RotorVelocity = SPN::PRO_ROTOR_POS_AND_VEL_VELOCITY as i32,
+    RotorPosition = SPN::PRO_ROTOR_POS_AND_VEL_POSITION as i32,
+    Velocity = SPN::PRO_POS_AND_VEL_VELOCITY as i32,
+    Position = SPN::PRO_POS_AND_VEL_POSITION as i32,
+    Acceleration = SPN::PRO_POS_AND_VEL_ACCELERATION as i32,
+    ControlMode = SPN::TALON_FX_CONTROL_MODE as i32,
+    MotionMagicIsRunning = SPN::PRO_PIDSTATE_ENABLES_IS_MOTION_MAGIC_RUNNING as i32,
+    DeviceEnable = SPN::PRO_PIDSTATE_ENABLES_DEVICE_ENABLE as i32,
+    BridgeOutput = SPN::PRO_MOTOR_OUTPUT_BRIDGE_TYPE_PUBLIC as i32,
+    ClosedLoopSlot = SPN::PRO_PIDOUTPUT_SLOT as i32,
+    ClosedLoopReferencePosition = SPN::PRO_PIDREF_PIDERR_PIDREF_POSITION as i32,
+    ClosedLoopReferenceVelocity = SPN::PRO_PIDREF_PIDERR_PIDREF_VELOCITY as i32,
+    ClosedLoopReferenceSlopePosition =
+        SPN::PRO_PIDREF_SLOPE_ECUTIME_REFERENCE_SLOPE_POSITION as i32,
+    ClosedLoopReferenceSlopeVelocity =
+        SPN::PRO_PIDREF_SLOPE_ECUTIME_REFERENCE_SLOPE_VELOCITY as i32,
+    ClosedLoopErrorPosition = SPN::PRO_PIDREF_PIDERR_PIDERR_POSITION as i32,
+    ClosedLoopErrorVelocity = SPN::PRO_PIDREF_PIDERR_PIDERR_VELOCITY as i32,
+    ClosedLoopProportionalOutputDutyCycle = SPN::PRO_PIDOUTPUT_PROPORTIONAL_OUTPUT_DC as i32,
+    ClosedLoopProportionalOutputVoltage = SPN::PRO_PIDOUTPUT_PROPORTIONAL_OUTPUT_V as i32,
+    ClosedLoopProportionalOutputTorqueCurrent = SPN::PRO_PIDOUTPUT_PROPORTIONAL_OUTPUT_A as i32,
+    ClosedLoopDerivativeOutputDutyCycle = SPN::PRO_PIDOUTPUT_DERIVATIVE_OUTPUT_DC as i32,
+    ClosedLoopDerivativeOutputVoltage = SPN::PRO_PIDOUTPUT_DERIVATIVE_OUTPUT_V as i32,
+    ClosedLoopDerivativeOutputTorqueCurrent = SPN::PRO_PIDOUTPUT_DERIVATIVE_OUTPUT_A as i32,
+    ClosedLoopOutputDutyCycle = SPN::PRO_PIDOUTPUT_OUTPUT_DC as i32,
+    ClosedLoopOutputVoltage = SPN::PRO_PIDOUTPUT_OUTPUT_V as i32,
+    ClosedLoopOutputTorqueCurrent = SPN::PRO_PIDOUTPUT_OUTPUT_A as i32,
+    ClosedLoopIntegratedOutputDutyCycle = SPN::PRO_PIDSTATE_ENABLES_INTEGRATED_ACCUM_DC as i32,
+    ClosedLoopIntegratedOutputVoltage = SPN::PRO_PIDSTATE_ENABLES_INTEGRATED_ACCUM_V as i32,
+    ClosedLoopIntegratedOutputTorqueCurrent = SPN::PRO_PIDSTATE_ENABLES_INTEGRATED_ACCUM_A as i32,
+    ClosedLoopFeedForwardDutyCycle = SPN::PRO_PIDSTATE_ENABLES_FEED_FORWARD_DC as i32,
+    ClosedLoopFeedForwardVoltage = SPN::PRO_PIDSTATE_ENABLES_FEED_FORWARD_V as i32,
+    ClosedLoopFeedForwardTorqueCurrent = SPN::PRO_PIDSTATE_ENABLES_FEED_FORWARD_A as i32,
+    DifferentialControlMode = SPN::TALON_FX_DIFFERENTIAL_CONTROL_MODE as i32,
+    DifferentialDutyCycle = SPN::PRO_MOTOR_OUTPUT_PIDSTATE_DIFF_DUTY_CYCLE as i32,
+    DifferentialTorqueCurrent = SPN::PRO_MOTOR_OUTPUT_PIDSTATE_DIFF_TORQUE_CURRENT as i32,
+    DifferentialAverageVelocity = SPN::PRO_AVG_POS_AND_VEL_VELOCITY as i32,
+    DifferentialAveragePosition = SPN::PRO_AVG_POS_AND_VEL_POSITION as i32,
+    DifferentialDifferenceVelocity = SPN::PRO_DIFF_POS_AND_VEL_VELOCITY as i32,
+    DifferentialDifferencePosition = SPN::PRO_DIFF_POS_AND_VEL_POSITION as i32,
+    DifferentialClosedLoopSlot = SPN::PRO_DIFF_PIDOUTPUT_SLOT as i32,
+    DifferentialClosedLoopReferencePosition = SPN::PRO_DIFF_PIDREF_PIDERR_PIDREF_POSITION as i32,
+    DifferentialClosedLoopReferenceVelocity = SPN::PRO_DIFF_PIDREF_PIDERR_PIDREF_VELOCITY as i32,
+    DifferentialClosedLoopReferenceSlopePosition =
+        SPN::PRO_DIFF_PIDREF_SLOPE_ECUTIME_REFERENCE_SLOPE_POSITION as i32,
+    DifferentialClosedLoopReferenceSlopeVelocity =
+        SPN::PRO_DIFF_PIDREF_SLOPE_ECUTIME_REFERENCE_SLOPE_VELOCITY as i32,
+    DifferentialClosedLoopErrorPosition = SPN::PRO_DIFF_PIDREF_PIDERR_PIDERR_POSITION as i32,
+    DifferentialClosedLoopErrorVelocity = SPN::PRO_DIFF_PIDREF_PIDERR_PIDERR_VELOCITY as i32,
+    DifferentialClosedLoopProportionalOutputDutyCycle =
+        SPN::PRO_DIFF_PIDOUTPUT_PROPORTIONAL_OUTPUT_DC as i32,
+    DifferentialClosedLoopProportionalOutputVoltage =
+        SPN::PRO_DIFF_PIDOUTPUT_PROPORTIONAL_OUTPUT_V as i32,
+    DifferentialClosedLoopProportionalOutputTorqueCurrent =
+        SPN::PRO_DIFF_PIDOUTPUT_PROPORTIONAL_OUTPUT_A as i32,
+    DifferentialClosedLoopDerivativeOutputDutyCycle =
+        SPN::PRO_DIFF_PIDOUTPUT_DERIVATIVE_OUTPUT_DC as i32,
+    DifferentialClosedLoopDerivativeOutputVoltage =
+        SPN::PRO_DIFF_PIDOUTPUT_DERIVATIVE_OUTPUT_V as i32,
+    DifferentialClosedLoopDerivativeOutputTorqueCurrent =
+        SPN::PRO_DIFF_PIDOUTPUT_DERIVATIVE_OUTPUT_A as i32,
+    DifferentialClosedLoopOutputDutyCycle = SPN::PRO_DIFF_PIDOUTPUT_OUTPUT_DC as i32,
+    DifferentialClosedLoopOutputVoltage = SPN::PRO_DIFF_PIDOUTPUT_OUTPUT_V as i32,
+    DifferentialClosedLoopOutputTorqueCurrent = SPN::PRO_DIFF_PIDOUTPUT_OUTPUT_A as i32,
+    DifferentialClosedLoopIntegratedOutputDutyCycle =
+        SPN::PRO_MOTOR_OUTPUT_PIDSTATE_DIFF_INTEGRATED_ACCUM_DC as i32,
+    DifferentialClosedLoopIntegratedOutputVoltage =
+        SPN::PRO_MOTOR_OUTPUT_PIDSTATE_DIFF_INTEGRATED_ACCUM_V as i32,
+    DifferentialClosedLoopIntegratedOutputTorqueCurrent =
+        SPN::PRO_MOTOR_OUTPUT_PIDSTATE_DIFF_INTEGRATED_ACCUM_A as i32,
+    DifferentialClosedLoopFeedForwardDutyCycle =
+        SPN::PRO_MOTOR_OUTPUT_PIDSTATE_DIFF_FEED_FORWARD_DC as i32,
+    DifferentialClosedLoopFeedForwardVoltage =
+        SPN::PRO_MOTOR_OUTPUT_PIDSTATE_DIFF_FEED_FORWARD_V as i32,
+    DifferentialClosedLoopFeedForwardTorqueCurrent =
+        SPN::PRO_MOTOR_OUTPUT_PIDSTATE_DIFF_FEED_FORWARD_A as i32,
+    SystemState = SPN::TALON_FX_SYSTEM_STATE as i32,
+    RobotEnable = SPN::ROBOT_ENABLED as i32,
+    IsPro = SPN::LICENSING_IS_PRO_LICENSED as i32,
+    IsSeasonPassed = SPN::LICENSING_IS_SEASON_PASSED as i32,
+    AllFaults = SPN::ALL_FAULTS as i32,
+    AllStickyFaults = SPN::ALL_STICKY_FAULTS as i32,
+    VersionFull = SPN::VERSION_FULL as i32,
+    CompliancyVersion = SPN::COMPLIANCY_VERSION as i32,
+    //(sticky)faults
+    StickyFaultHardware = SPN::STICKY_FAULT_HARDWARE as i32,
+    FaultHardware = SPN::FAULT_HARDWARE as i32,
+    StickyFaultProcTemp = SPN::STICKY_FAULT_PROC_TEMP as i32,
+    FaultProcTemp = SPN::FAULT_PROC_TEMP as i32,
+    StickyFaultDeviceTemp = SPN::STICKY_FAULT_DEVICE_TEMP as i32,
+    FaultDeviceTemp = SPN::FAULT_DEVICE_TEMP as i32,
+    StickyFaultUnderVoltage = SPN::STICKY_FAULT_UNDERVOLTAGE as i32,
+    FaultUnderVoltage = SPN::FAULT_UNDERVOLTAGE as i32,
+    StickyFaultBootDuringEnable = SPN::STICKY_FAULT_BOOT_DURING_ENABLE as i32,
+    FaultBootDuringEnable = SPN::FAULT_BOOT_DURING_ENABLE as i32,
+    StickyFaultUnliscensedFeatureInUse = SPN::STICKY_FAULT_UNLICENSED_FEATURE_IN_USE as i32,
+    FaultUnliscensedFeatureInUse = SPN::FAULT_UNLICENSED_FEATURE_IN_USE as i32,
+    StickyFaultBridgeBrownout = SPN::STICKY_FAULT_TALONFX_BRIDGE_BROWNOUT as i32,
+    FaultBridgeBrownout = SPN::FAULT_TALONFX_BRIDGE_BROWNOUT as i32,
+    StickyFaultRemoteSensorReset = SPN::STICKY_FAULT_TALONFX_REMOTE_SENSOR_RESET as i32,
+    FaultRemoteSensorReset = SPN::FAULT_TALONFX_REMOTE_SENSOR_RESET as i32,
+    StickyFaultMissingDifferentialFX = SPN::STICKY_FAULT_TALONFX_MISSING_DIFFERENTIAL_FX as i32,
+    FaultMissingDifferentialFX = SPN::FAULT_TALONFX_MISSING_DIFFERENTIAL_FX as i32,
+    StickyFaultRemoteSensorPosOverflow =
+        SPN::STICKY_FAULT_TALONFX_REMOTE_SENSOR_POS_OVERFLOW as i32,
+    FaultRemoteSensorPosOverflow = SPN::FAULT_TALONFX_REMOTE_SENSOR_POS_OVERFLOW as i32,
+    StickyFaultOverSupplyV = SPN::STICKY_FAULT_TALONFX_OVER_SUPPLYV as i32,
+    FaultOverSupplyV = SPN::FAULT_TALONFX_OVER_SUPPLYV as i32,
+    StickyFaultUnstableSupplyV = SPN::STICKY_FAULT_TALONFX_UNSTABLE_SUPPLYV as i32,
+    FaultUnstableSupplyV = SPN::FAULT_TALONFX_UNSTABLE_SUPPLYV as i32,
+    StickyFaultReverseHardLimit = SPN::STICKY_FAULT_TALONFX_REVERSE_HARD_LIMIT as i32,
+    FaultReverseHardLimit = SPN::FAULT_TALONFX_REVERSE_HARD_LIMIT as i32,
+    StickyFaultForwardHardLimit = SPN::STICKY_FAULT_TALONFX_FORWARD_HARD_LIMIT as i32,
+    FaultForwardHardLimit = SPN::FAULT_TALONFX_FORWARD_HARD_LIMIT as i32,
+    StickyFaultReverseSoftLimit = SPN::STICKY_FAULT_TALONFX_REVERSE_SOFT_LIMIT as i32,
+    FaultReverseSoftLimit = SPN::FAULT_TALONFX_REVERSE_SOFT_LIMIT as i32,
+    StickyFaultForwardSoftLimit = SPN::STICKY_FAULT_TALONFX_FORWARD_SOFT_LIMIT as i32,
+    FaultForwardSoftLimit = SPN::FAULT_TALONFX_FORWARD_SOFT_LIMIT as i32,
+    StickyFaultMissingRemoteSensor = SPN::STICKY_FAULT_TALONFX_MISSING_REMOTE_SENSOR as i32,
+    FaultMissingRemoteSensor = SPN::FAULT_TALONFX_MISSING_REMOTE_SENSOR as i32,
+    StickyFaultFusedSensorOutOfSync = SPN::STICKY_FAULT_TALONFX_FUSED_SENSOR_OUT_OF_SYNC as i32,
+    FaultFusedSensorOutOfSync = SPN::FAULT_TALONFX_FUSED_SENSOR_OUT_OF_SYNC as i32,
+    StickyFaultStatorCurrLimit = SPN::STICKY_FAULT_TALONFX_STATOR_CURR_LIMIT as i32,
+    FaultStatorCurrLimit = SPN::FAULT_TALONFX_STATOR_CURR_LIMIT as i32,
+    StickyFaultSupplyCurrLimit = SPN::STICKY_FAULT_TALONFX_SUPPLY_CURR_LIMIT as i32,
+    FaultSupplyCurrLimit = SPN::FAULT_TALONFX_SUPPLY_CURR_LIMIT as i32,
+    StickyFaultUsingFusedCANcoderWhileUnlicensed =
+        SPN::STICKY_FAULT_TALONFX_USING_FUSED_CCWHILE_UNLICENSED as i32,
+    FaultUsingFusedCANcoderWhileUnlicensed =
+        SPN::FAULT_TALONFX_USING_FUSED_CCWHILE_UNLICENSED as i32,
+}
+
+pub struct TalonFXSignal<T: SPNValue> {
+    identifier: DeviceIdentifier,
+    field: TalonFXSignalField,
+    cache: Option<Aweak<RwLock<TalonFXCache>>>,
+    phantom: std::marker::PhantomData<T>,
+}
+impl<T: SPNValue> TalonFXSignal<T> {
+    fn new(
+        identifier: DeviceIdentifier,
+        field: TalonFXSignalField,
+        cache: Aweak<RwLock<TalonFXCache>>,
+    ) -> Self {
+        Self {
+            identifier,
+            field,
+            cache: Some(cache),
+            phantom: std::marker::PhantomData,
+        }
+    }
+
+    fn new_cold(identifier: DeviceIdentifier, field: TalonFXSignalField) -> Self {
+        Self {
+            identifier,
+            field,
+            cache: None,
+            phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<T: SPNValue> BaseSignal<T> for TalonFXSignal<T> {
+    fn value(&self) -> Status<SignalValue<T>> {
+        if let Some(cache) = &self.cache {
+            let cache = cache.upgrade().ok_or(StatusCode::CouldNotValidate)?;
+            let cache = cache.read();
+            let value = match self.field {
+                TalonFXSignalField::MotorVoltage => cache.motor_voltage,
+                TalonFXSignalField::ForwardLimit => cache.forward_limit,
+                TalonFXSignalField::ReverseLimit => cache.reverse_limit,
+                TalonFXSignalField::AppliedRotorPolarity => cache.applied_rotor_polarity,
+                TalonFXSignalField::DutyCycle => cache.duty_cycle,
+                TalonFXSignalField::TorqueCurrent => cache.torque_current,
+                TalonFXSignalField::StatorCurrent => cache.stator_current,
+                TalonFXSignalField::SupplyCurrent => cache.supply_current,
+                TalonFXSignalField::SupplyVoltage => cache.supply_voltage,
+                TalonFXSignalField::DeviceTemp => cache.device_temp,
+                TalonFXSignalField::ProcessorTemp => cache.processor_temp,
+                TalonFXSignalField::RotorVelocity => cache.rotor_velocity,
+                TalonFXSignalField::RotorPosition => cache.rotor_position,
+                TalonFXSignalField::Velocity => cache.velocity,
+                TalonFXSignalField::Position => cache.position,
+                TalonFXSignalField::Acceleration => cache.acceleration,
+                TalonFXSignalField::ControlMode => cache.control_mode,
+                TalonFXSignalField::MotionMagicIsRunning => cache.motion_magic_is_running,
+                TalonFXSignalField::DeviceEnable => cache.device_enable,
+                TalonFXSignalField::BridgeOutput => cache.bridge_output,
+                TalonFXSignalField::ClosedLoopSlot => cache.closed_loop_slot,
+                TalonFXSignalField::ClosedLoopReferencePosition => {
+                    cache.closed_loop_reference_position
+                }
+                TalonFXSignalField::ClosedLoopReferenceVelocity => {
+                    cache.closed_loop_reference_velocity
+                }
+                TalonFXSignalField::ClosedLoopReferenceSlopePosition => {
+                    cache.closed_loop_reference_slope_position
+                }
+                TalonFXSignalField::ClosedLoopReferenceSlopeVelocity => {
+                    cache.closed_loop_reference_slope_velocity
+                }
+                TalonFXSignalField::ClosedLoopErrorPosition => cache.closed_loop_error_position,
+                TalonFXSignalField::ClosedLoopErrorVelocity => cache.closed_loop_error_velocity,
+                TalonFXSignalField::ClosedLoopProportionalOutputDutyCycle => {
+                    cache.closed_loop_proportional_output_duty_cycle
+                }
+                TalonFXSignalField::ClosedLoopProportionalOutputVoltage => {
+                    cache.closed_loop_proportional_output_voltage
+                }
+                TalonFXSignalField::ClosedLoopProportionalOutputTorqueCurrent => {
+                    cache.closed_loop_proportional_output_torque_current
+                }
+                TalonFXSignalField::ClosedLoopDerivativeOutputDutyCycle => {
+                    cache.closed_loop_derivative_output_duty_cycle
+                }
+                TalonFXSignalField::ClosedLoopDerivativeOutputVoltage => {
+                    cache.closed_loop_derivative_output_voltage
+                }
+                TalonFXSignalField::ClosedLoopDerivativeOutputTorqueCurrent => {
+                    cache.closed_loop_derivative_output_torque_current
+                }
+                TalonFXSignalField::ClosedLoopOutputDutyCycle => {
+                    cache.closed_loop_output_duty_cycle
+                }
+                TalonFXSignalField::ClosedLoopOutputVoltage => cache.closed_loop_output_voltage,
+                TalonFXSignalField::ClosedLoopOutputTorqueCurrent => {
+                    cache.closed_loop_output_torque_current
+                }
+                TalonFXSignalField::ClosedLoopIntegratedOutputDutyCycle => {
+                    cache.closed_loop_integrated_output_duty_cycle
+                }
+                TalonFXSignalField::ClosedLoopIntegratedOutputVoltage => {
+                    cache.closed_loop_integrated_output_voltage
+                }
+                TalonFXSignalField::ClosedLoopIntegratedOutputTorqueCurrent => {
+                    cache.closed_loop_integrated_output_torque_current
+                }
+                TalonFXSignalField::ClosedLoopFeedForwardDutyCycle => {
+                    cache.closed_loop_feed_forward_duty_cycle
+                }
+                TalonFXSignalField::ClosedLoopFeedForwardVoltage => {
+                    cache.closed_loop_feed_forward_voltage
+                }
+                TalonFXSignalField::ClosedLoopFeedForwardTorqueCurrent => {
+                    cache.closed_loop_feed_forward_torque_current
+                }
+                TalonFXSignalField::DifferentialControlMode => cache.differential_control_mode,
+                TalonFXSignalField::DifferentialDutyCycle => cache.differential_duty_cycle,
+                TalonFXSignalField::DifferentialTorqueCurrent => cache.differential_torque_current,
+                TalonFXSignalField::DifferentialAverageVelocity => {
+                    cache.differential_average_velocity
+                }
+                TalonFXSignalField::DifferentialAveragePosition => {
+                    cache.differential_average_position
+                }
+                TalonFXSignalField::DifferentialDifferenceVelocity => {
+                    cache.differential_difference_velocity
+                }
+                TalonFXSignalField::DifferentialDifferencePosition => {
+                    cache.differential_difference_position
+                }
+                TalonFXSignalField::DifferentialClosedLoopSlot => {
+                    cache.differential_closed_loop_slot
+                }
+                TalonFXSignalField::DifferentialClosedLoopReferencePosition => {
+                    cache.differential_closed_loop_reference_position
+                }
+                TalonFXSignalField::DifferentialClosedLoopReferenceVelocity => {
+                    cache.differential_closed_loop_reference_velocity
+                }
+                TalonFXSignalField::DifferentialClosedLoopReferenceSlopePosition => {
+                    cache.differential_closed_loop_reference_slope_position
+                }
+                TalonFXSignalField::DifferentialClosedLoopReferenceSlopeVelocity => {
+                    cache.differential_closed_loop_reference_slope_velocity
+                }
+                TalonFXSignalField::DifferentialClosedLoopErrorPosition => {
+                    cache.differential_closed_loop_error_position
+                }
+                TalonFXSignalField::DifferentialClosedLoopErrorVelocity => {
+                    cache.differential_closed_loop_error_velocity
+                }
+                TalonFXSignalField::DifferentialClosedLoopProportionalOutputDutyCycle => {
+                    cache.differential_closed_loop_proportional_output_duty_cycle
+                }
+                TalonFXSignalField::DifferentialClosedLoopProportionalOutputVoltage => {
+                    cache.differential_closed_loop_proportional_output_voltage
+                }
+                TalonFXSignalField::DifferentialClosedLoopProportionalOutputTorqueCurrent => {
+                    cache.differential_closed_loop_proportional_output_torque_current
+                }
+                TalonFXSignalField::DifferentialClosedLoopDerivativeOutputDutyCycle => {
+                    cache.differential_closed_loop_derivative_output_duty_cycle
+                }
+                TalonFXSignalField::DifferentialClosedLoopDerivativeOutputVoltage => {
+                    cache.differential_closed_loop_derivative_output_voltage
+                }
+                TalonFXSignalField::DifferentialClosedLoopDerivativeOutputTorqueCurrent => {
+                    cache.differential_closed_loop_derivative_output_torque_current
+                }
+                TalonFXSignalField::DifferentialClosedLoopOutputDutyCycle => {
+                    cache.differential_closed_loop_output_duty_cycle
+                }
+                TalonFXSignalField::DifferentialClosedLoopOutputVoltage => {
+                    cache.differential_closed_loop_output_voltage
+                }
+                TalonFXSignalField::DifferentialClosedLoopOutputTorqueCurrent => {
+                    cache.differential_closed_loop_output_torque_current
+                }
+                TalonFXSignalField::DifferentialClosedLoopIntegratedOutputDutyCycle => {
+                    cache.differential_closed_loop_integrated_output_duty_cycle
+                }
+                TalonFXSignalField::DifferentialClosedLoopIntegratedOutputVoltage => {
+                    cache.differential_closed_loop_integrated_output_voltage
+                }
+                TalonFXSignalField::DifferentialClosedLoopIntegratedOutputTorqueCurrent => {
+                    cache.differential_closed_loop_integrated_output_torque_current
+                }
+                TalonFXSignalField::DifferentialClosedLoopFeedForwardDutyCycle => {
+                    cache.differential_closed_loop_feed_forward_duty_cycle
+                }
+                TalonFXSignalField::DifferentialClosedLoopFeedForwardVoltage => {
+                    cache.differential_closed_loop_feed_forward_voltage
+                }
+                TalonFXSignalField::DifferentialClosedLoopFeedForwardTorqueCurrent => {
+                    cache.differential_closed_loop_feed_forward_torque_current
+                }
+                _ => unreachable!("This should not happen, this is a cold signal."),
+            };
+            value.read()
+        } else {
+            let ret = native::request_signal_value_single(
+                native::SignalMeta {
+                    can_bus: self.identifier.canbus.clone(),
+                    timeout: crate::DEFAULT_TIMEOUT,
+                },
+                native::SignalSpecifier {
+                    hash: self.identifier.hash.0,
+                    spn: self.get_spn(),
+                },
+            )?;
+            Ok(SignalValue::<T>::from(ret))
+        }
+    }
+
+    fn get_spn(&self) -> SPN {
+        (self.field as i32).try_into().expect("Invalid SPN")
+    }
+
+    fn get_device_hash(&self) -> u32 {
+        self.identifier.hash.0
+    }
+
+    fn set_update_freq(&self, freq_hz: f64) -> Status<()> {
+        native::set_update_freq(
+            native::SignalMeta {
+                can_bus: self.identifier.canbus.clone(),
+                timeout: crate::DEFAULT_TIMEOUT,
+            },
+            native::SignalSpecifier {
+                hash: self.identifier.hash.0,
+                spn: self.get_spn(),
+            },
+            freq_hz,
+        )
+    }
+}
+
+impl<T: SPNValue> RefreshableStatusSignal for TalonFXSignal<T> {
+    fn refresh(&self) -> Status<()> {
+        if self.cache.is_none() {
+            return Ok(());
+        }
+        let ret = native::request_signal_value_single(
+            native::SignalMeta {
+                can_bus: self.identifier.canbus.clone(),
+                timeout: crate::DEFAULT_TIMEOUT,
+            },
+            native::SignalSpecifier {
+                hash: self.identifier.hash.0,
+                spn: self.get_spn(),
+            },
+        );
+        self.update_cache(ret)
+    }
+
+    fn device_identifier(&self) -> &DeviceIdentifier {
+        &self.identifier
+    }
+
+    fn spn(&self) -> SPN {
+        self.get_spn()
+    }
+
+    fn update_cache(&self, value: Status<SignalValueRaw>) -> Status<()> {
+        let Some(cache) = &self.cache else {
+            return value.map(|_| ());
+        };
+        let cache = cache.upgrade().ok_or(StatusCode::InvalidDeviceDescriptor)?;
+        let mut cache = cache.write();
+        let entry = match self.field {
+            TalonFXSignalField::MotorVoltage => &mut cache.motor_voltage,
+            TalonFXSignalField::ForwardLimit => &mut cache.forward_limit,
+            TalonFXSignalField::ReverseLimit => &mut cache.reverse_limit,
+            TalonFXSignalField::AppliedRotorPolarity => &mut cache.applied_rotor_polarity,
+            TalonFXSignalField::DutyCycle => &mut cache.duty_cycle,
+            TalonFXSignalField::TorqueCurrent => &mut cache.torque_current,
+            TalonFXSignalField::StatorCurrent => &mut cache.stator_current,
+            TalonFXSignalField::SupplyCurrent => &mut cache.supply_current,
+            TalonFXSignalField::SupplyVoltage => &mut cache.supply_voltage,
+            TalonFXSignalField::DeviceTemp => &mut cache.device_temp,
+            TalonFXSignalField::ProcessorTemp => &mut cache.processor_temp,
+            TalonFXSignalField::RotorVelocity => &mut cache.rotor_velocity,
+            TalonFXSignalField::RotorPosition => &mut cache.rotor_position,
+            TalonFXSignalField::Velocity => &mut cache.velocity,
+            TalonFXSignalField::Position => &mut cache.position,
+            TalonFXSignalField::Acceleration => &mut cache.acceleration,
+            TalonFXSignalField::ControlMode => &mut cache.control_mode,
+            TalonFXSignalField::MotionMagicIsRunning => &mut cache.motion_magic_is_running,
+            TalonFXSignalField::DeviceEnable => &mut cache.device_enable,
+            TalonFXSignalField::BridgeOutput => &mut cache.bridge_output,
+            TalonFXSignalField::ClosedLoopSlot => &mut cache.closed_loop_slot,
+            TalonFXSignalField::ClosedLoopReferencePosition => {
+                &mut cache.closed_loop_reference_position
+            }
+            TalonFXSignalField::ClosedLoopReferenceVelocity => {
+                &mut cache.closed_loop_reference_velocity
+            }
+            TalonFXSignalField::ClosedLoopReferenceSlopePosition => {
+                &mut cache.closed_loop_reference_slope_position
+            }
+            TalonFXSignalField::ClosedLoopReferenceSlopeVelocity => {
+                &mut cache.closed_loop_reference_slope_velocity
+            }
+            TalonFXSignalField::ClosedLoopErrorPosition => &mut cache.closed_loop_error_position,
+            TalonFXSignalField::ClosedLoopErrorVelocity => &mut cache.closed_loop_error_velocity,
+            TalonFXSignalField::ClosedLoopProportionalOutputDutyCycle => {
+                &mut cache.closed_loop_proportional_output_duty_cycle
+            }
+            TalonFXSignalField::ClosedLoopProportionalOutputVoltage => {
+                &mut cache.closed_loop_proportional_output_voltage
+            }
+            TalonFXSignalField::ClosedLoopProportionalOutputTorqueCurrent => {
+                &mut cache.closed_loop_proportional_output_torque_current
+            }
+            TalonFXSignalField::ClosedLoopDerivativeOutputDutyCycle => {
+                &mut cache.closed_loop_derivative_output_duty_cycle
+            }
+            TalonFXSignalField::ClosedLoopDerivativeOutputVoltage => {
+                &mut cache.closed_loop_derivative_output_voltage
+            }
+            TalonFXSignalField::ClosedLoopDerivativeOutputTorqueCurrent => {
+                &mut cache.closed_loop_derivative_output_torque_current
+            }
+            TalonFXSignalField::ClosedLoopOutputDutyCycle => {
+                &mut cache.closed_loop_output_duty_cycle
+            }
+            TalonFXSignalField::ClosedLoopOutputVoltage => &mut cache.closed_loop_output_voltage,
+            TalonFXSignalField::ClosedLoopOutputTorqueCurrent => {
+                &mut cache.closed_loop_output_torque_current
+            }
+            TalonFXSignalField::ClosedLoopIntegratedOutputDutyCycle => {
+                &mut cache.closed_loop_integrated_output_duty_cycle
+            }
+            TalonFXSignalField::ClosedLoopIntegratedOutputVoltage => {
+                &mut cache.closed_loop_integrated_output_voltage
+            }
+            TalonFXSignalField::ClosedLoopIntegratedOutputTorqueCurrent => {
+                &mut cache.closed_loop_integrated_output_torque_current
+            }
+            TalonFXSignalField::ClosedLoopFeedForwardDutyCycle => {
+                &mut cache.closed_loop_feed_forward_duty_cycle
+            }
+            TalonFXSignalField::ClosedLoopFeedForwardVoltage => {
+                &mut cache.closed_loop_feed_forward_voltage
+            }
+            TalonFXSignalField::ClosedLoopFeedForwardTorqueCurrent => {
+                &mut cache.closed_loop_feed_forward_torque_current
+            }
+            TalonFXSignalField::DifferentialControlMode => &mut cache.differential_control_mode,
+            TalonFXSignalField::DifferentialDutyCycle => &mut cache.differential_duty_cycle,
+            TalonFXSignalField::DifferentialTorqueCurrent => &mut cache.differential_torque_current,
+            TalonFXSignalField::DifferentialAverageVelocity => {
+                &mut cache.differential_average_velocity
+            }
+            TalonFXSignalField::DifferentialAveragePosition => {
+                &mut cache.differential_average_position
+            }
+            TalonFXSignalField::DifferentialDifferenceVelocity => {
+                &mut cache.differential_difference_velocity
+            }
+            TalonFXSignalField::DifferentialDifferencePosition => {
+                &mut cache.differential_difference_position
+            }
+            TalonFXSignalField::DifferentialClosedLoopSlot => {
+                &mut cache.differential_closed_loop_slot
+            }
+            TalonFXSignalField::DifferentialClosedLoopReferencePosition => {
+                &mut cache.differential_closed_loop_reference_position
+            }
+            TalonFXSignalField::DifferentialClosedLoopReferenceVelocity => {
+                &mut cache.differential_closed_loop_reference_velocity
+            }
+            TalonFXSignalField::DifferentialClosedLoopReferenceSlopePosition => {
+                &mut cache.differential_closed_loop_reference_slope_position
+            }
+            TalonFXSignalField::DifferentialClosedLoopReferenceSlopeVelocity => {
+                &mut cache.differential_closed_loop_reference_slope_velocity
+            }
+            TalonFXSignalField::DifferentialClosedLoopErrorPosition => {
+                &mut cache.differential_closed_loop_error_position
+            }
+            TalonFXSignalField::DifferentialClosedLoopErrorVelocity => {
+                &mut cache.differential_closed_loop_error_velocity
+            }
+            TalonFXSignalField::DifferentialClosedLoopProportionalOutputDutyCycle => {
+                &mut cache.differential_closed_loop_proportional_output_duty_cycle
+            }
+            TalonFXSignalField::DifferentialClosedLoopProportionalOutputVoltage => {
+                &mut cache.differential_closed_loop_proportional_output_voltage
+            }
+            TalonFXSignalField::DifferentialClosedLoopProportionalOutputTorqueCurrent => {
+                &mut cache.differential_closed_loop_proportional_output_torque_current
+            }
+            TalonFXSignalField::DifferentialClosedLoopDerivativeOutputDutyCycle => {
+                &mut cache.differential_closed_loop_derivative_output_duty_cycle
+            }
+            TalonFXSignalField::DifferentialClosedLoopDerivativeOutputVoltage => {
+                &mut cache.differential_closed_loop_derivative_output_voltage
+            }
+            TalonFXSignalField::DifferentialClosedLoopDerivativeOutputTorqueCurrent => {
+                &mut cache.differential_closed_loop_derivative_output_torque_current
+            }
+            TalonFXSignalField::DifferentialClosedLoopOutputDutyCycle => {
+                &mut cache.differential_closed_loop_output_duty_cycle
+            }
+            TalonFXSignalField::DifferentialClosedLoopOutputVoltage => {
+                &mut cache.differential_closed_loop_output_voltage
+            }
+            TalonFXSignalField::DifferentialClosedLoopOutputTorqueCurrent => {
+                &mut cache.differential_closed_loop_output_torque_current
+            }
+            TalonFXSignalField::DifferentialClosedLoopIntegratedOutputDutyCycle => {
+                &mut cache.differential_closed_loop_integrated_output_duty_cycle
+            }
+            TalonFXSignalField::DifferentialClosedLoopIntegratedOutputVoltage => {
+                &mut cache.differential_closed_loop_integrated_output_voltage
+            }
+            TalonFXSignalField::DifferentialClosedLoopIntegratedOutputTorqueCurrent => {
+                &mut cache.differential_closed_loop_integrated_output_torque_current
+            }
+            TalonFXSignalField::DifferentialClosedLoopFeedForwardDutyCycle => {
+                &mut cache.differential_closed_loop_feed_forward_duty_cycle
+            }
+            TalonFXSignalField::DifferentialClosedLoopFeedForwardVoltage => {
+                &mut cache.differential_closed_loop_feed_forward_voltage
+            }
+            TalonFXSignalField::DifferentialClosedLoopFeedForwardTorqueCurrent => {
+                &mut cache.differential_closed_loop_feed_forward_torque_current
+            }
+            _ => unreachable!("This should not happen, this is a cold signal."),
+        };
+        entry.update(value)
+    }
+}
+
+signal_setup! {
+    device: TalonFX,
+    signal: TalonFXSignal,
+    fields: TalonFXSignalField
+}
+
+signal! {motor_voltage -> MotorVoltage<Volt>}
+signal! {forward_limit -> ForwardLimit<ForwardLimitValue>}
+signal! {reverse_limit -> ReverseLimit<ReverseLimitValue>}
+signal! {applied_rotor_polarity -> AppliedRotorPolarity<AppliedRotorPolarityValue>}
+signal! {duty_cycle -> DutyCycle<f64>}
+signal! {torque_current -> TorqueCurrent<Amp>}
+signal! {stator_current -> StatorCurrent<Amp>}
+signal! {supply_current -> SupplyCurrent<Amp>}
+signal! {supply_voltage -> SupplyVoltage<Volt>}
+signal! {device_temp -> DeviceTemp<Celsius>}
+signal! {processor_temp -> ProcessorTemp<Celsius>}
+signal! {rotor_velocity -> RotorVelocity<RotationPerSec>}
+signal! {rotor_position -> RotorPosition<Rotation>}
+signal! {velocity -> Velocity<RotationPerSec>}
+signal! {position -> Position<Rotation>}
+signal! {acceleration -> Acceleration<RotationPerSecSqr>}
+signal! {control_mode -> ControlMode<ControlModeValue>}
+signal! {motion_magic_is_running -> MotionMagicIsRunning<MotionMagicIsRunningValue>}
+signal! {device_enable -> DeviceEnable<DeviceEnableValue>}
+signal! {bridge_output -> BridgeOutput<BridgeOutputValue>}
+signal! {closed_loop_slot -> ClosedLoopSlot<i32>}
+signal! {closed_loop_reference_position -> ClosedLoopReferencePosition<Rotation>}
+signal! {closed_loop_reference_velocity -> ClosedLoopReferenceVelocity<RotationPerSec>}
+signal! {closed_loop_reference_slope_position -> ClosedLoopReferenceSlopePosition<RotationPerSec>}
+signal! {closed_loop_reference_slope_velocity -> ClosedLoopReferenceSlopeVelocity<RotationPerSecSqr>}
+signal! {closed_loop_error_position -> ClosedLoopErrorPosition<Rotation>}
+signal! {closed_loop_error_velocity -> ClosedLoopErrorVelocity<RotationPerSec>}
+signal! {closed_loop_proportional_output_duty_cycle -> ClosedLoopProportionalOutputDutyCycle<f64>}
+signal! {closed_loop_proportional_output_voltage -> ClosedLoopProportionalOutputVoltage<Volt>}
+signal! {closed_loop_proportional_output_torque_current -> ClosedLoopProportionalOutputTorqueCurrent<Amp>}
+signal! {closed_loop_derivative_output_duty_cycle -> ClosedLoopDerivativeOutputDutyCycle<f64>}
+signal! {closed_loop_derivative_output_voltage -> ClosedLoopDerivativeOutputVoltage<Volt>}
+signal! {closed_loop_derivative_output_torque_current -> ClosedLoopDerivativeOutputTorqueCurrent<Amp>}
+signal! {closed_loop_output_duty_cycle -> ClosedLoopOutputDutyCycle<f64>}
+signal! {closed_loop_output_voltage -> ClosedLoopOutputVoltage<Volt>}
+signal! {closed_loop_output_torque_current -> ClosedLoopOutputTorqueCurrent<Amp>}
+signal! {closed_loop_integrated_output_duty_cycle -> ClosedLoopIntegratedOutputDutyCycle<f64>}
+signal! {closed_loop_integrated_output_voltage -> ClosedLoopIntegratedOutputVoltage<Volt>}
+signal! {closed_loop_integrated_output_torque_current -> ClosedLoopIntegratedOutputTorqueCurrent<Amp>}
+signal! {closed_loop_feed_forward_duty_cycle -> ClosedLoopFeedForwardDutyCycle<f64>}
+signal! {closed_loop_feed_forward_voltage -> ClosedLoopFeedForwardVoltage<Volt>}
+signal! {closed_loop_feed_forward_torque_current -> ClosedLoopFeedForwardTorqueCurrent<Amp>}
+signal! {differential_control_mode -> DifferentialControlMode<DifferentialControlModeValue>}
+signal! {differential_duty_cycle -> DifferentialDutyCycle<f64>}
+signal! {differential_torque_current -> DifferentialTorqueCurrent<Amp>}
+signal! {differential_average_velocity -> DifferentialAverageVelocity<RotationPerSec>}
+signal! {differential_average_position -> DifferentialAveragePosition<Rotation>}
+signal! {differential_difference_velocity -> DifferentialDifferenceVelocity<RotationPerSec>}
+signal! {differential_difference_position -> DifferentialDifferencePosition<Rotation>}
+signal! {differential_closed_loop_slot -> DifferentialClosedLoopSlot<i32>}
+signal! {differential_closed_loop_reference_position -> DifferentialClosedLoopReferencePosition<Rotation>}
+signal! {differential_closed_loop_reference_velocity -> DifferentialClosedLoopReferenceVelocity<RotationPerSec>}
+signal! {differential_closed_loop_reference_slope_position -> DifferentialClosedLoopReferenceSlopePosition<RotationPerSec>}
+signal! {differential_closed_loop_reference_slope_velocity -> DifferentialClosedLoopReferenceSlopeVelocity<RotationPerSecSqr>}
+signal! {differential_closed_loop_error_position -> DifferentialClosedLoopErrorPosition<Rotation>}
+signal! {differential_closed_loop_error_velocity -> DifferentialClosedLoopErrorVelocity<RotationPerSec>}
+signal! {differential_closed_loop_proportional_output_duty_cycle -> DifferentialClosedLoopProportionalOutputDutyCycle<f64>}
+signal! {differential_closed_loop_proportional_output_voltage -> DifferentialClosedLoopProportionalOutputVoltage<Volt>}
+signal! {differential_closed_loop_proportional_output_torque_current -> DifferentialClosedLoopProportionalOutputTorqueCurrent<Amp>}
+signal! {differential_closed_loop_derivative_output_duty_cycle -> DifferentialClosedLoopDerivativeOutputDutyCycle<f64>}
+signal! {differential_closed_loop_derivative_output_voltage -> DifferentialClosedLoopDerivativeOutputVoltage<Volt>}
+signal! {differential_closed_loop_derivative_output_torque_current -> DifferentialClosedLoopDerivativeOutputTorqueCurrent<Amp>}
+signal! {differential_closed_loop_output_duty_cycle -> DifferentialClosedLoopOutputDutyCycle<f64>}
+signal! {differential_closed_loop_output_voltage -> DifferentialClosedLoopOutputVoltage<Volt>}
+signal! {differential_closed_loop_output_torque_current -> DifferentialClosedLoopOutputTorqueCurrent<Amp>}
+signal! {differential_closed_loop_integrated_output_duty_cycle -> DifferentialClosedLoopIntegratedOutputDutyCycle<f64>}
+signal! {differential_closed_loop_integrated_output_voltage -> DifferentialClosedLoopIntegratedOutputVoltage<Volt>}
+signal! {differential_closed_loop_integrated_output_torque_current -> DifferentialClosedLoopIntegratedOutputTorqueCurrent<Amp>}
+signal! {differential_closed_loop_feed_forward_duty_cycle -> DifferentialClosedLoopFeedForwardDutyCycle<f64>}
+signal! {differential_closed_loop_feed_forward_voltage -> DifferentialClosedLoopFeedForwardVoltage<Volt>}
+signal! {differential_closed_loop_feed_forward_torque_current -> DifferentialClosedLoopFeedForwardTorqueCurrent<Amp>}
+
+cold_signal! {system_state -> SystemState<SystemStateValue>}
+cold_signal! {robot_enable -> RobotEnable<RobotEnableValue>}
+cold_signal! {is_pro -> IsPro<bool>}
+cold_signal! {is_season_passed -> IsSeasonPassed<bool>}
+cold_signal! {faults -> AllFaults<FaultSet>}
+cold_signal! {sticky_faults -> AllStickyFaults<StickyFaultSet>}
+cold_signal! {version -> VersionFull<FirmwareVersion>}
+cold_signal! {compliancy_version -> CompliancyVersion<i32>}
+
+cold_signal! {sticky_fault_hardware -> StickyFaultHardware<bool>}
+cold_signal! {fault_hardware -> FaultHardware<bool>}
+cold_signal! {sticky_fault_proc_temp -> StickyFaultProcTemp<bool>}
+cold_signal! {fault_proc_temp -> FaultProcTemp<bool>}
+cold_signal! {sticky_fault_device_temp -> StickyFaultDeviceTemp<bool>}
+cold_signal! {fault_device_temp -> FaultDeviceTemp<bool>}
+cold_signal! {sticky_fault_under_voltage -> StickyFaultUnderVoltage<bool>}
+cold_signal! {fault_under_voltage -> FaultUnderVoltage<bool>}
+cold_signal! {sticky_fault_boot_during_enable -> StickyFaultBootDuringEnable<bool>}
+cold_signal! {fault_boot_during_enable -> FaultBootDuringEnable<bool>}
+cold_signal! {sticky_fault_unliscensed_feature_in_use -> StickyFaultUnliscensedFeatureInUse<bool>}
+cold_signal! {fault_unliscensed_feature_in_use -> FaultUnliscensedFeatureInUse<bool>}
+cold_signal! {sticky_fault_bridge_brownout -> StickyFaultBridgeBrownout<bool>}
+cold_signal! {fault_bridge_brownout -> FaultBridgeBrownout<bool>}
+cold_signal! {sticky_fault_remote_sensor_reset -> StickyFaultRemoteSensorReset<bool>}
+cold_signal! {fault_remote_sensor_reset -> FaultRemoteSensorReset<bool>}
+cold_signal! {sticky_fault_missing_differential_fx -> StickyFaultMissingDifferentialFX<bool>}
+cold_signal! {fault_missing_differential_fx -> FaultMissingDifferentialFX<bool>}
+cold_signal! {sticky_fault_remote_sensor_pos_overflow -> StickyFaultRemoteSensorPosOverflow<bool>}
+cold_signal! {fault_remote_sensor_pos_overflow -> FaultRemoteSensorPosOverflow<bool>}
+cold_signal! {sticky_fault_over_supply_v -> StickyFaultOverSupplyV<bool>}
+cold_signal! {fault_over_supply_v -> FaultOverSupplyV<bool>}
+cold_signal! {sticky_fault_unstable_supply_v -> StickyFaultUnstableSupplyV<bool>}
+cold_signal! {fault_unstable_supply_v -> FaultUnstableSupplyV<bool>}
+cold_signal! {sticky_fault_reverse_hard_limit -> StickyFaultReverseHardLimit<bool>}
+cold_signal! {fault_reverse_hard_limit -> FaultReverseHardLimit<bool>}
+cold_signal! {sticky_fault_forward_hard_limit -> StickyFaultForwardHardLimit<bool>}
+cold_signal! {fault_forward_hard_limit -> FaultForwardHardLimit<bool>}
+cold_signal! {sticky_fault_reverse_soft_limit -> StickyFaultReverseSoftLimit<bool>}
+cold_signal! {fault_reverse_soft_limit -> FaultReverseSoftLimit<bool>}
+cold_signal! {sticky_fault_forward_soft_limit -> StickyFaultForwardSoftLimit<bool>}
+cold_signal! {fault_forward_soft_limit -> FaultForwardSoftLimit<bool>}
+cold_signal! {sticky_fault_missing_remote_sensor -> StickyFaultMissingRemoteSensor<bool>}
+cold_signal! {fault_missing_remote_sensor -> FaultMissingRemoteSensor<bool>}
+cold_signal! {sticky_fault_fused_sensor_out_of_sync -> StickyFaultFusedSensorOutOfSync<bool>}
+cold_signal! {fault_fused_sensor_out_of_sync -> FaultFusedSensorOutOfSync<bool>}
+cold_signal! {sticky_fault_stator_curr_limit -> StickyFaultStatorCurrLimit<bool>}
+cold_signal! {fault_stator_curr_limit -> FaultStatorCurrLimit<bool>}
+cold_signal! {sticky_fault_supply_curr_limit -> StickyFaultSupplyCurrLimit<bool>}
+cold_signal! {fault_supply_curr_limit -> FaultSupplyCurrLimit<bool>}
+cold_signal! {sticky_fault_using_fused_cancoder_while_unlicensed -> StickyFaultUsingFusedCANcoderWhileUnlicensed<bool>}
+cold_signal! {fault_using_fused_cancoder_while_unlicensed -> FaultUsingFusedCANcoderWhileUnlicensed<bool>}
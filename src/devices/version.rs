@@ -0,0 +1,121 @@
+use crate::{
+    __sealed,
+    error::StatusCode,
+    signals::{native, SPNValue},
+    spn::SPN,
+    Status,
+};
+
+use super::DeviceIdentifier;
+
+/// The firmware version of a device, decoded from [`SPN::VERSION_FULL`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct FirmwareVersion {
+    pub major: u8,
+    pub minor: u8,
+    pub bugfix: u8,
+    pub build: u8,
+}
+impl FirmwareVersion {
+    pub const fn new(major: u8, minor: u8, bugfix: u8, build: u8) -> Self {
+        Self {
+            major,
+            minor,
+            bugfix,
+            build,
+        }
+    }
+
+    /// Decodes the version the same way the device packs it,
+    /// one byte per component with major in the most significant byte
+    pub const fn from_bits(bits: u32) -> Self {
+        Self {
+            major: (bits >> 24) as u8,
+            minor: (bits >> 16) as u8,
+            bugfix: (bits >> 8) as u8,
+            build: bits as u8,
+        }
+    }
+
+    pub const fn bits(&self) -> u32 {
+        (self.major as u32) << 24
+            | (self.minor as u32) << 16
+            | (self.bugfix as u32) << 8
+            | self.build as u32
+    }
+
+    /// The version of the phoenix library this crate is running against
+    pub fn library() -> Self {
+        let version = unsafe { ctre_phoenix6_sys::c_ctre_phoenix6_unmanaged_get_phoenix_version() };
+        Self {
+            major: (version >> 16) as u8,
+            minor: (version >> 8) as u8,
+            bugfix: version as u8,
+            build: 0,
+        }
+    }
+}
+impl std::fmt::Display for FirmwareVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}.{}.{}.{}",
+            self.major, self.minor, self.bugfix, self.build
+        )
+    }
+}
+impl __sealed::Sealed for FirmwareVersion {}
+impl SPNValue for FirmwareVersion {
+    fn try_from_f64(value: f64) -> Status<Self> {
+        Ok(Self::from_bits(value as u32))
+    }
+
+    fn to_f64(self) -> f64 {
+        self.bits() as f64
+    }
+}
+
+/// The config/control compliancy the phoenix library this crate is running against expects
+pub fn api_compliancy() -> i32 {
+    unsafe { ctre_phoenix6_sys::c_ctre_phoenix6_unmanaged_get_api_compliancy() }
+}
+
+/// Compares the firmware on the device with the phoenix library.
+///
+/// A device on an older major version or with a lower compliancy reports
+/// [`StatusCode::FirmwareTooOld`], a device on a newer major version reports
+/// [`StatusCode::FirmwareTooNew`] and a device with a higher compliancy than
+/// the library knows about reports [`StatusCode::ApiTooOld`].
+pub(crate) fn check_compatibility(device: &DeviceIdentifier, timeout: f64) -> Status<()> {
+    let meta = || native::SignalMeta {
+        can_bus: device.canbus.clone(),
+        timeout,
+    };
+    let version = native::request_signal_value_single(
+        meta(),
+        native::SignalSpecifier {
+            hash: device.hash.0,
+            spn: SPN::VERSION_FULL,
+        },
+    )?;
+    let version = FirmwareVersion::try_from_f64(version.value)?;
+    let library = FirmwareVersion::library();
+    match version.major.cmp(&library.major) {
+        std::cmp::Ordering::Less => return Err(StatusCode::FirmwareTooOld),
+        std::cmp::Ordering::Greater => return Err(StatusCode::FirmwareTooNew),
+        std::cmp::Ordering::Equal => {}
+    }
+
+    let compliancy = native::request_signal_value_single(
+        meta(),
+        native::SignalSpecifier {
+            hash: device.hash.0,
+            spn: SPN::COMPLIANCY_VERSION,
+        },
+    )?;
+    match (compliancy.value as i32).cmp(&api_compliancy()) {
+        std::cmp::Ordering::Less => Err(StatusCode::FirmwareTooOld),
+        std::cmp::Ordering::Greater => Err(StatusCode::ApiTooOld),
+        std::cmp::Ordering::Equal => Ok(()),
+    }
+}
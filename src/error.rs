@@ -356,6 +356,8 @@ pub enum StatusCode {
     //Custom to rust
     #[error("Could not deserialize string config")]
     CouldNotDeserializeString = -99001,
+    #[error("A signal logger session is already active, drop it before starting another")]
+    LoggerSessionActive = -99002,
 }
 pub trait StatusCodeType {
     fn to_result(self) -> Result<(), StatusCode>;
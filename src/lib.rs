@@ -1,3 +1,5 @@
+#[cfg(feature = "async")]
+pub(crate) mod blocking;
 pub mod controls;
 pub mod devices;
 pub mod error;
@@ -0,0 +1,95 @@
+//! Async versions of the blocking signal calls, the requests run on an internal
+//! thread pool so they work on any executor.
+
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use flume::r#async::RecvStream;
+use frclib_core::units::time::Time;
+use futures_core::Stream;
+
+use crate::{blocking, Status};
+
+use super::{
+    apply_bus_responses, bus_requests, execute_bus_requests, queued::QueuedSignal,
+    RefreshableStatusSignal, SPNValue, SignalValue, SignalValueRaw,
+};
+
+/// Refreshes the value of the signal, see [`RefreshableStatusSignal::refresh`]
+pub async fn refresh(signal: &(dyn RefreshableStatusSignal + Sync)) -> Status<()> {
+    request_all(crate::DEFAULT_TIMEOUT, &[signal])
+        .await
+        .pop()
+        .unwrap_or(Ok(()))
+}
+
+/// Waits up to `timeout` for every signal to receive new data, see [`super::wait_for_all`]
+pub async fn wait_for_all(
+    timeout: impl Time,
+    signals: &[&(dyn RefreshableStatusSignal + Sync)],
+) -> Vec<Status<()>> {
+    request_all(timeout.to_seconds().value(), signals).await
+}
+
+/// Updates every signal without waiting for new data, see [`super::refresh_all`]
+pub async fn refresh_all(signals: &[&(dyn RefreshableStatusSignal + Sync)]) -> Vec<Status<()>> {
+    request_all(0.0, signals).await
+}
+
+async fn request_all(
+    timeout: f64,
+    signals: &[&(dyn RefreshableStatusSignal + Sync)],
+) -> Vec<Status<()>> {
+    let requests = bus_requests(timeout, signals);
+    let (requests, responses) = match blocking::run(move || {
+        let responses = execute_bus_requests(&requests);
+        Ok((requests, responses))
+    })
+    .await
+    {
+        Ok(ret) => ret,
+        Err(err) => return vec![Err(err); signals.len()],
+    };
+    apply_bus_responses(signals, &requests, responses)
+}
+
+/// Every sample of a [`QueuedSignal`] as a [`Stream`], created with [`super::BaseSignal::stream`].
+///
+/// The signal stops being polled when the stream is dropped.
+pub struct SignalStream<T: SPNValue> {
+    // kept so the signal stays registered with the queue thread
+    _signal: QueuedSignal<T>,
+    stream: RecvStream<'static, SignalValueRaw>,
+}
+impl<T: SPNValue> SignalStream<T> {
+    pub(crate) fn new(signal: QueuedSignal<T>) -> Self {
+        let stream = signal.receiver().clone().into_stream();
+        Self {
+            _signal: signal,
+            stream,
+        }
+    }
+}
+// nothing is pinned structurally, the receiver stream is `Unpin` regardless of `T`
+impl<T: SPNValue> Unpin for SignalStream<T> {}
+impl<T: SPNValue> Stream for SignalStream<T> {
+    type Item = SignalValue<T>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            match Pin::new(&mut this.stream).poll_next(cx) {
+                Poll::Ready(Some(raw)) => {
+                    // a value that does not fit `T` is skipped like in `QueuedStatusSignal::drain`
+                    if let Ok(value) = raw.try_cast() {
+                        return Poll::Ready(Some(value));
+                    }
+                }
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
@@ -0,0 +1,410 @@
+use crate::{devices::Model, spn::SPN, Status};
+
+use super::{
+    __sealed,
+    logger::{LogValue, Loggable},
+    SPNValue,
+};
+
+macro_rules! faults {
+    ($(
+        $(#[doc = $doc:literal])*
+        $variant:ident = $bit:literal {
+            fault: $fault:ident,
+            sticky: $sticky:ident,
+            clear: $clear:expr,
+            models: [$($model:ident),*] $(,)?
+        }
+    ),* $(,)?) => {
+        /// A single fault that can be reported by a CTRE device.
+        ///
+        /// The discriminant of each variant is its bit position in the
+        /// [`SPN::ALL_FAULTS`] and [`SPN::ALL_STICKY_FAULTS`] bitfields,
+        /// which follows the order of the `FAULT_*` SPNs.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #[repr(u8)]
+        pub enum Fault {
+            $(
+                $(#[doc = $doc])*
+                $variant = $bit,
+            )*
+        }
+        impl Fault {
+            /// Every fault known to this library, in bit order.
+            pub const ALL: &'static [Fault] = &[$(Fault::$variant),*];
+
+            /// The SPN of the live fault signal
+            pub fn fault_spn(&self) -> SPN {
+                match self {
+                    $(Fault::$variant => SPN::$fault,)*
+                }
+            }
+
+            /// The SPN of the sticky fault signal
+            pub fn sticky_spn(&self) -> SPN {
+                match self {
+                    $(Fault::$variant => SPN::$sticky,)*
+                }
+            }
+
+            /// The SPN used to clear this sticky fault,
+            /// `None` if the device does not allow this fault to be cleared on its own
+            pub fn clear_spn(&self) -> Option<SPN> {
+                match self {
+                    $(Fault::$variant => $clear,)*
+                }
+            }
+
+            /// Returns true if devices of `model` can report this fault
+            pub fn applies_to(&self, model: Model) -> bool {
+                match self {
+                    $(Fault::$variant => faults!(@models model, $($model),*),)*
+                }
+            }
+
+            fn name(&self) -> &'static str {
+                match self {
+                    $(Fault::$variant => stringify!($variant),)*
+                }
+            }
+        }
+    };
+    (@models $model:ident, ) => {
+        true
+    };
+    (@models $model:ident, $($m:ident),+) => {
+        false $(|| $model == Model::$m())+
+    };
+}
+
+faults! {
+    /// Hardware fault occurred
+    Hardware = 0 {
+        fault: FAULT_HARDWARE,
+        sticky: STICKY_FAULT_HARDWARE,
+        clear: Some(SPN::CLEAR_STICKY_FAULT_HARDWARE),
+        models: [],
+    },
+    /// Processor temperature exceeded limit
+    ProcTemp = 1 {
+        fault: FAULT_PROC_TEMP,
+        sticky: STICKY_FAULT_PROC_TEMP,
+        clear: Some(SPN::CLEAR_STICKY_FAULT_PROC_TEMP),
+        models: [talon_fx],
+    },
+    /// Device temperature exceeded limit
+    DeviceTemp = 2 {
+        fault: FAULT_DEVICE_TEMP,
+        sticky: STICKY_FAULT_DEVICE_TEMP,
+        clear: Some(SPN::CLEAR_STICKY_FAULT_DEVICE_TEMP),
+        models: [talon_fx],
+    },
+    /// Device supply voltage dropped to near brownout levels
+    Undervoltage = 3 {
+        fault: FAULT_UNDERVOLTAGE,
+        sticky: STICKY_FAULT_UNDERVOLTAGE,
+        clear: Some(SPN::CLEAR_STICKY_FAULT_UNDERVOLTAGE),
+        models: [],
+    },
+    /// Device boot while detecting the enable signal
+    BootDuringEnable = 4 {
+        fault: FAULT_BOOT_DURING_ENABLE,
+        sticky: STICKY_FAULT_BOOT_DURING_ENABLE,
+        clear: Some(SPN::CLEAR_STICKY_FAULT_BOOT_DURING_ENABLE),
+        models: [],
+    },
+    /// An unlicensed feature is in use, device may not behave as expected
+    UnlicensedFeatureInUse = 5 {
+        fault: FAULT_UNLICENSED_FEATURE_IN_USE,
+        sticky: STICKY_FAULT_UNLICENSED_FEATURE_IN_USE,
+        clear: None,
+        models: [],
+    },
+    /// Bootup checks failed: Accelerometer
+    Pigeon2BootupAccel = 6 {
+        fault: FAULT_PIGEON2_BOOTUP_ACCEL,
+        sticky: STICKY_FAULT_PIGEON2_BOOTUP_ACCEL,
+        clear: Some(SPN::CLEAR_STICKY_FAULT_PIGEON2_BOOTUP_ACCEL),
+        models: [pigeon2],
+    },
+    /// Bootup checks failed: Gyroscope
+    Pigeon2BootupGyros = 7 {
+        fault: FAULT_PIGEON2_BOOTUP_GYROS,
+        sticky: STICKY_FAULT_PIGEON2_BOOTUP_GYROS,
+        clear: Some(SPN::CLEAR_STICKY_FAULT_PIGEON2_BOOTUP_GYROS),
+        models: [pigeon2],
+    },
+    /// Bootup checks failed: Magnetometer
+    Pigeon2BootupMagne = 8 {
+        fault: FAULT_PIGEON2_BOOTUP_MAGNE,
+        sticky: STICKY_FAULT_PIGEON2_BOOTUP_MAGNE,
+        clear: Some(SPN::CLEAR_STICKY_FAULT_PIGEON2_BOOTUP_MAGNE),
+        models: [pigeon2],
+    },
+    /// Motion Detected during bootup
+    Pigeon2BootIntoMotion = 9 {
+        fault: FAULT_PIGEON2_BOOT_INTO_MOTION,
+        sticky: STICKY_FAULT_PIGEON2_BOOT_INTO_MOTION,
+        clear: Some(SPN::CLEAR_STICKY_FAULT_PIGEON2_BOOT_INTO_MOTION),
+        models: [pigeon2],
+    },
+    /// Motion stack data acquisition was slower than expected
+    Pigeon2DataAcquiredLate = 10 {
+        fault: FAULT_PIGEON2_DATA_ACQUIRED_LATE,
+        sticky: STICKY_FAULT_PIGEON2_DATA_ACQUIRED_LATE,
+        clear: Some(SPN::CLEAR_STICKY_FAULT_PIGEON2_DATA_ACQUIRED_LATE),
+        models: [pigeon2],
+    },
+    /// Motion stack loop time was slower than expected
+    Pigeon2LoopTimeSlow = 11 {
+        fault: FAULT_PIGEON2_LOOP_TIME_SLOW,
+        sticky: STICKY_FAULT_PIGEON2_LOOP_TIME_SLOW,
+        clear: Some(SPN::CLEAR_STICKY_FAULT_PIGEON2_LOOP_TIME_SLOW),
+        models: [pigeon2],
+    },
+    /// Magnetometer values are saturated
+    Pigeon2SaturatedMagne = 12 {
+        fault: FAULT_PIGEON2_SATURATED_MAGNE,
+        sticky: STICKY_FAULT_PIGEON2_SATURATED_MAGNE,
+        clear: Some(SPN::CLEAR_STICKY_FAULT_PIGEON2_SATURATED_MAGNE),
+        models: [pigeon2],
+    },
+    /// Accelerometer values are saturated
+    Pigeon2SaturatedAccel = 13 {
+        fault: FAULT_PIGEON2_SATURATED_ACCEL,
+        sticky: STICKY_FAULT_PIGEON2_SATURATED_ACCEL,
+        clear: Some(SPN::CLEAR_STICKY_FAULT_PIGEON2_SATURATED_ACCEL),
+        models: [pigeon2],
+    },
+    /// Gyroscope values are saturated
+    Pigeon2SaturatedGyros = 14 {
+        fault: FAULT_PIGEON2_SATURATED_GYROS,
+        sticky: STICKY_FAULT_PIGEON2_SATURATED_GYROS,
+        clear: Some(SPN::CLEAR_STICKY_FAULT_PIGEON2_SATURATED_GYROS),
+        models: [pigeon2],
+    },
+    /// The magnet distance is not correct or magnet is missing
+    CanCoderBadMagnet = 15 {
+        fault: FAULT_CANCODER_BAD_MAGNET,
+        sticky: STICKY_FAULT_CANCODER_BAD_MAGNET,
+        clear: Some(SPN::CLEAR_STICKY_FAULT_CANCODER_BAD_MAGNET),
+        models: [cancoder],
+    },
+    /// Bridge was disabled most likely due to supply voltage dropping too low
+    BridgeBrownout = 16 {
+        fault: FAULT_TALONFX_BRIDGE_BROWNOUT,
+        sticky: STICKY_FAULT_TALONFX_BRIDGE_BROWNOUT,
+        clear: Some(SPN::CLEAR_STICKY_FAULT_TALONFX_BRIDGE_BROWNOUT),
+        models: [talon_fx],
+    },
+    /// The remote sensor has reset
+    RemoteSensorReset = 17 {
+        fault: FAULT_TALONFX_REMOTE_SENSOR_RESET,
+        sticky: STICKY_FAULT_TALONFX_REMOTE_SENSOR_RESET,
+        clear: Some(SPN::CLEAR_STICKY_FAULT_TALONFX_REMOTE_SENSOR_RESET),
+        models: [talon_fx],
+    },
+    /// The remote Talon FX used for differential control is not present on CAN Bus
+    MissingDifferentialFX = 18 {
+        fault: FAULT_TALONFX_MISSING_DIFFERENTIAL_FX,
+        sticky: STICKY_FAULT_TALONFX_MISSING_DIFFERENTIAL_FX,
+        clear: Some(SPN::CLEAR_STICKY_FAULT_TALONFX_MISSING_DIFFERENTIAL_FX),
+        models: [talon_fx],
+    },
+    /// The remote sensor position has overflowed
+    RemoteSensorPosOverflow = 19 {
+        fault: FAULT_TALONFX_REMOTE_SENSOR_POS_OVERFLOW,
+        sticky: STICKY_FAULT_TALONFX_REMOTE_SENSOR_POS_OVERFLOW,
+        clear: Some(SPN::CLEAR_STICKY_FAULT_TALONFX_REMOTE_SENSOR_POS_OVERFLOW),
+        models: [talon_fx],
+    },
+    /// Supply Voltage has exceeded the maximum voltage rating of device
+    OverSupplyV = 20 {
+        fault: FAULT_TALONFX_OVER_SUPPLYV,
+        sticky: STICKY_FAULT_TALONFX_OVER_SUPPLYV,
+        clear: Some(SPN::CLEAR_STICKY_FAULT_TALONFX_OVER_SUPPLYV),
+        models: [talon_fx],
+    },
+    /// Supply Voltage is unstable
+    UnstableSupplyV = 21 {
+        fault: FAULT_TALONFX_UNSTABLE_SUPPLYV,
+        sticky: STICKY_FAULT_TALONFX_UNSTABLE_SUPPLYV,
+        clear: Some(SPN::CLEAR_STICKY_FAULT_TALONFX_UNSTABLE_SUPPLYV),
+        models: [talon_fx],
+    },
+    /// Reverse limit switch has been asserted, output is set to neutral
+    ReverseHardLimit = 22 {
+        fault: FAULT_TALONFX_REVERSE_HARD_LIMIT,
+        sticky: STICKY_FAULT_TALONFX_REVERSE_HARD_LIMIT,
+        clear: Some(SPN::CLEAR_STICKY_FAULT_TALONFX_REVERSE_HARD_LIMIT),
+        models: [talon_fx],
+    },
+    /// Forward limit switch has been asserted, output is set to neutral
+    ForwardHardLimit = 23 {
+        fault: FAULT_TALONFX_FORWARD_HARD_LIMIT,
+        sticky: STICKY_FAULT_TALONFX_FORWARD_HARD_LIMIT,
+        clear: Some(SPN::CLEAR_STICKY_FAULT_TALONFX_FORWARD_HARD_LIMIT),
+        models: [talon_fx],
+    },
+    /// Reverse soft limit has been asserted, output is set to neutral
+    ReverseSoftLimit = 24 {
+        fault: FAULT_TALONFX_REVERSE_SOFT_LIMIT,
+        sticky: STICKY_FAULT_TALONFX_REVERSE_SOFT_LIMIT,
+        clear: Some(SPN::CLEAR_STICKY_FAULT_TALONFX_REVERSE_SOFT_LIMIT),
+        models: [talon_fx],
+    },
+    /// Forward soft limit has been asserted, output is set to neutral
+    ForwardSoftLimit = 25 {
+        fault: FAULT_TALONFX_FORWARD_SOFT_LIMIT,
+        sticky: STICKY_FAULT_TALONFX_FORWARD_SOFT_LIMIT,
+        clear: Some(SPN::CLEAR_STICKY_FAULT_TALONFX_FORWARD_SOFT_LIMIT),
+        models: [talon_fx],
+    },
+    /// The remote sensor is not present on CAN Bus
+    MissingRemoteSensor = 28 {
+        fault: FAULT_TALONFX_MISSING_REMOTE_SENSOR,
+        sticky: STICKY_FAULT_TALONFX_MISSING_REMOTE_SENSOR,
+        clear: Some(SPN::CLEAR_STICKY_FAULT_TALONFX_MISSING_REMOTE_SENSOR),
+        models: [talon_fx],
+    },
+    /// The remote sensor used for fusion has fallen out of sync to the local sensor
+    FusedSensorOutOfSync = 29 {
+        fault: FAULT_TALONFX_FUSED_SENSOR_OUT_OF_SYNC,
+        sticky: STICKY_FAULT_TALONFX_FUSED_SENSOR_OUT_OF_SYNC,
+        clear: Some(SPN::CLEAR_STICKY_FAULT_TALONFX_FUSED_SENSOR_OUT_OF_SYNC),
+        models: [talon_fx],
+    },
+    /// Stator current limit occured
+    StatorCurrLimit = 30 {
+        fault: FAULT_TALONFX_STATOR_CURR_LIMIT,
+        sticky: STICKY_FAULT_TALONFX_STATOR_CURR_LIMIT,
+        clear: Some(SPN::CLEAR_STICKY_FAULT_TALONFX_STATOR_CURR_LIMIT),
+        models: [talon_fx],
+    },
+    /// Supply current limit occured
+    SupplyCurrLimit = 31 {
+        fault: FAULT_TALONFX_SUPPLY_CURR_LIMIT,
+        sticky: STICKY_FAULT_TALONFX_SUPPLY_CURR_LIMIT,
+        clear: Some(SPN::CLEAR_STICKY_FAULT_TALONFX_SUPPLY_CURR_LIMIT),
+        models: [talon_fx],
+    },
+    /// Using Fused CANcoder feature while unlicensed, device has fallen back to remote CANcoder
+    UsingFusedCANcoderWhileUnlicensed = 32 {
+        fault: FAULT_TALONFX_USING_FUSED_CCWHILE_UNLICENSED,
+        sticky: STICKY_FAULT_TALONFX_USING_FUSED_CCWHILE_UNLICENSED,
+        clear: None,
+        models: [talon_fx],
+    },
+}
+
+impl Fault {
+    const fn mask(self) -> u64 {
+        1 << self as u8
+    }
+}
+
+impl std::fmt::Display for Fault {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+macro_rules! fault_set {
+    ($(#[doc = $doc:literal])* $name:ident) => {
+        $(#[doc = $doc])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+        pub struct $name(u64);
+        impl $name {
+            /// Creates a set from the raw bitfield reported by the device,
+            /// bits that do not belong to a known [`Fault`] are kept but never yielded
+            pub const fn from_bits(bits: u64) -> Self {
+                Self(bits)
+            }
+
+            /// The raw bitfield reported by the device
+            pub const fn bits(&self) -> u64 {
+                self.0
+            }
+
+            /// Returns true if `fault` is set
+            pub fn contains(&self, fault: Fault) -> bool {
+                self.0 & fault.mask() != 0
+            }
+
+            /// Returns true if no known fault is set
+            pub fn is_empty(&self) -> bool {
+                self.iter().next().is_none()
+            }
+
+            /// The number of known faults that are set
+            pub fn len(&self) -> usize {
+                self.iter().count()
+            }
+
+            pub fn insert(&mut self, fault: Fault) {
+                self.0 |= fault.mask();
+            }
+
+            pub fn remove(&mut self, fault: Fault) {
+                self.0 &= !fault.mask();
+            }
+
+            /// Iterates over every known fault that is set, in bit order
+            pub fn iter(&self) -> impl Iterator<Item = Fault> + '_ {
+                Fault::ALL.iter().copied().filter(|fault| self.contains(*fault))
+            }
+        }
+        impl FromIterator<Fault> for $name {
+            fn from_iter<I: IntoIterator<Item = Fault>>(iter: I) -> Self {
+                let mut set = Self::default();
+                for fault in iter {
+                    set.insert(fault);
+                }
+                set
+            }
+        }
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{} [", stringify!($name))?;
+                for (i, fault) in self.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{fault}")?;
+                }
+                write!(f, "]")
+            }
+        }
+        impl __sealed::Sealed for $name {}
+        impl SPNValue for $name {
+            fn try_from_f64(value: f64) -> Status<Self> {
+                Ok(Self(value as u64))
+            }
+
+            fn to_f64(self) -> f64 {
+                self.0 as f64
+            }
+        }
+        impl Loggable for $name {
+            fn to_log_value(&self) -> LogValue<'_> {
+                LogValue::Integer(self.0 as i64, "")
+            }
+        }
+    };
+}
+
+fault_set! {
+    /// The set of faults currently active on a device,
+    /// decoded from [`SPN::ALL_FAULTS`]
+    FaultSet
+}
+fault_set! {
+    /// The set of sticky faults latched on a device,
+    /// decoded from [`SPN::ALL_STICKY_FAULTS`]
+    StickyFaultSet
+}
+impl From<StickyFaultSet> for FaultSet {
+    fn from(sticky: StickyFaultSet) -> Self {
+        Self::from_bits(sticky.bits())
+    }
+}
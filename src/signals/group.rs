@@ -0,0 +1,262 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use frclib_core::units::time::Time;
+
+use crate::{error::StatusCode, Status};
+
+use super::{
+    native::{self, SignalSpecifier},
+    BaseSignal, CachedValue, RefreshableStatusSignal, SPNValue, SignalValue, SignalValueRaw,
+};
+
+static NEXT_OWNER: AtomicU64 = AtomicU64::new(0);
+
+/// A unique id for a builder that hands out [`SignalHandle`]s
+pub(super) fn next_owner() -> u64 {
+    NEXT_OWNER.fetch_add(1, Ordering::Relaxed)
+}
+
+/// A typed index into a [`SignalGroup`], returned when a signal is added to a [`SignalGroupBuilder`].
+///
+/// A handle only reads values from the group or odometry samples of the builder that created it.
+#[derive(Debug)]
+pub struct SignalHandle<T: SPNValue> {
+    owner: u64,
+    index: usize,
+    phantom: std::marker::PhantomData<T>,
+}
+impl<T: SPNValue> Clone for SignalHandle<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<T: SPNValue> Copy for SignalHandle<T> {}
+impl<T: SPNValue> SignalHandle<T> {
+    pub(super) fn new(owner: u64, index: usize) -> Self {
+        Self {
+            owner,
+            index,
+            phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// The entry of `values` this handle points to, fails with [`StatusCode::InvalidParamValue`]
+    /// if the handle was created for another container than `owner`
+    pub(super) fn get<'a, V>(&self, owner: u64, values: &'a [V]) -> Status<&'a V> {
+        if self.owner != owner {
+            return Err(StatusCode::InvalidParamValue);
+        }
+        values.get(self.index).ok_or(StatusCode::InvalidParamValue)
+    }
+}
+
+type GroupedSignal = Box<dyn RefreshableStatusSignal + Send + Sync>;
+
+pub struct SignalGroupBuilder {
+    owner: u64,
+    signals: Vec<GroupedSignal>,
+}
+impl Default for SignalGroupBuilder {
+    fn default() -> Self {
+        Self {
+            owner: next_owner(),
+            signals: Vec::new(),
+        }
+    }
+}
+impl SignalGroupBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a signal to the group, the returned handle is used to read its value from the built group
+    pub fn add<T, S>(&mut self, signal: S) -> SignalHandle<T>
+    where
+        T: SPNValue,
+        S: BaseSignal<T> + RefreshableStatusSignal + Send + Sync + 'static,
+    {
+        self.signals.push(Box::new(signal));
+        SignalHandle::new(self.owner, self.signals.len() - 1)
+    }
+
+    pub fn build(self) -> SignalGroup {
+        let mut batches: Vec<BusBatch> = Vec::new();
+        let mut update_list = Vec::with_capacity(self.signals.len());
+        for (index, signal) in self.signals.iter().enumerate() {
+            let identifier = signal.device_identifier();
+            let specifier = SignalSpecifier {
+                hash: identifier.hash.0,
+                spn: signal.spn(),
+            };
+            update_list.push((identifier.canbus.clone(), specifier));
+            let batch = match batches
+                .iter_mut()
+                .position(|batch| batch.can_bus == identifier.canbus)
+            {
+                Some(i) => &mut batches[i],
+                None => {
+                    batches.push(BusBatch::new(identifier.canbus.clone()));
+                    batches.last_mut().expect("A batch was just pushed")
+                }
+            };
+            batch.push(index, specifier);
+        }
+        SignalGroup {
+            owner: self.owner,
+            latest: vec![CachedValue::default(); self.signals.len()],
+            signals: self.signals,
+            batches,
+            update_list,
+        }
+    }
+}
+
+/// The hash/SPN arrays and response buffers for every signal of a group on a single CAN bus
+struct BusBatch {
+    can_bus: String,
+    members: Vec<usize>,
+    hashes: Vec<u32>,
+    spns: Vec<u32>,
+    values: Vec<f64>,
+    can_timestamps: Vec<f64>,
+    software_timestamps: Vec<f64>,
+    device_timestamps: Vec<f64>,
+}
+impl BusBatch {
+    fn new(can_bus: String) -> Self {
+        Self {
+            can_bus,
+            members: Vec::new(),
+            hashes: Vec::new(),
+            spns: Vec::new(),
+            values: Vec::new(),
+            can_timestamps: Vec::new(),
+            software_timestamps: Vec::new(),
+            device_timestamps: Vec::new(),
+        }
+    }
+
+    fn push(&mut self, index: usize, specifier: SignalSpecifier) {
+        self.members.push(index);
+        self.hashes.push(specifier.hash);
+        self.spns.push(specifier.spn as u32);
+        self.values.push(0.0);
+        self.can_timestamps.push(0.0);
+        self.software_timestamps.push(0.0);
+        self.device_timestamps.push(0.0);
+    }
+
+    fn request(&mut self, timeout: f64) -> Status<()> {
+        native::request_signal_values_into(
+            &self.can_bus,
+            timeout,
+            &self.hashes,
+            &self.spns,
+            &mut self.values,
+            &mut self.can_timestamps,
+            &mut self.software_timestamps,
+            &mut self.device_timestamps,
+        )
+    }
+
+    fn response(&self, i: usize) -> SignalValueRaw {
+        SignalValueRaw {
+            value: self.values[i],
+            can_timestamp: self.can_timestamps[i],
+            software_timestamp: self.software_timestamps[i],
+            device_timestamp: self.device_timestamps[i],
+        }
+    }
+}
+
+/// A fixed set of signals, possibly across devices and CAN buses,
+/// that are always read together.
+///
+/// All buffers are allocated when the group is built so reading the group does not allocate.
+pub struct SignalGroup {
+    owner: u64,
+    signals: Vec<GroupedSignal>,
+    batches: Vec<BusBatch>,
+    latest: Vec<CachedValue>,
+    update_list: Vec<(String, SignalSpecifier)>,
+}
+impl SignalGroup {
+    pub fn builder() -> SignalGroupBuilder {
+        SignalGroupBuilder::new()
+    }
+
+    /// Reads the latest value of every signal without waiting for new data
+    pub fn refresh(&mut self) -> Status<()> {
+        self.request(0.0)
+    }
+
+    /// Waits up to `timeout` for every signal to receive new data
+    pub fn wait(&mut self, timeout: impl Time) -> Status<()> {
+        self.request(timeout.to_seconds().value())
+    }
+
+    /// Sets the update frequency of every signal in the group
+    pub fn set_update_frequency(&self, freq_hz: f64) -> Status<()> {
+        native::set_update_freq_all::<frclib_core::units::time::Second>(
+            &self.update_list,
+            freq_hz,
+            None,
+        )
+    }
+
+    /// The value of the signal as of the last [`refresh`](Self::refresh) or [`wait`](Self::wait)
+    pub fn value<T: SPNValue>(&self, handle: SignalHandle<T>) -> Status<SignalValue<T>> {
+        let cached = handle.get(self.owner, &self.latest)?;
+        Ok(SignalValue {
+            status: cached.status,
+            ..cached.raw.try_cast()?
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.signals.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.signals.is_empty()
+    }
+
+    /// Requests every bus, an error on one bus does not stop the others from being read,
+    /// the first error is returned
+    fn request(&mut self, timeout: f64) -> Status<()> {
+        let mut result = Ok(());
+        for batch in self.batches.iter_mut() {
+            let requested = batch.request(timeout);
+            for (i, &member) in batch.members.iter().enumerate() {
+                let response = requested.map(|_| batch.response(i));
+                let _ = self.latest[member].update(response);
+                if let Err(err) = self.signals[member].update_cache(response) {
+                    result = result.and(Err(err));
+                }
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handle_reads_only_from_its_owner() {
+        let owner = next_owner();
+        let other = next_owner();
+        let handle = SignalHandle::<f64>::new(owner, 1);
+        let values = [1.0, 2.0];
+        assert_eq!(handle.get(owner, &values), Ok(&2.0));
+        assert_eq!(
+            handle.get(other, &values),
+            Err(StatusCode::InvalidParamValue)
+        );
+        assert_eq!(
+            SignalHandle::<f64>::new(owner, 2).get(owner, &values),
+            Err(StatusCode::InvalidParamValue)
+        );
+    }
+}
@@ -0,0 +1,349 @@
+use std::collections::VecDeque;
+
+use parking_lot::{Mutex, MutexGuard};
+
+use crate::{devices::DeviceIdentifier, spn::SPN, Status};
+
+use super::{
+    BaseSignal, QueuedStatusSignal, RefreshableStatusSignal, SPNValue, SignalValue, SignalValueRaw,
+};
+
+/// Which timestamp of a [`SignalValue`] a [`SignalHistory`] is ordered by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TimestampSource {
+    /// The CAN timestamp, the most accurate time the value was sent,
+    /// falls back to the software timestamp for values without one like on a CANivore,
+    /// the same as [`SignalValue::timestamp`]
+    #[default]
+    Can,
+    /// The time the value was received by this process
+    Software,
+}
+impl TimestampSource {
+    fn of<T: SPNValue>(&self, value: &SignalValue<T>) -> f64 {
+        match self {
+            TimestampSource::Can => value.timestamp(),
+            TimestampSource::Software => value.software_timestamp,
+        }
+    }
+}
+
+/// A ring buffer of the most recent samples of a signal, ordered by timestamp,
+/// used to look up what a value was at a time in the past
+#[derive(Debug, Clone)]
+pub struct SignalHistory<T: SPNValue> {
+    samples: VecDeque<SignalValue<T>>,
+    capacity: usize,
+    source: TimestampSource,
+}
+impl<T: SPNValue> SignalHistory<T> {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            samples: VecDeque::with_capacity(capacity),
+            capacity,
+            source: TimestampSource::default(),
+        }
+    }
+
+    /// Orders the history by `source` instead of the CAN timestamp, this clears the history
+    pub fn keyed_by(mut self, source: TimestampSource) -> Self {
+        self.source = source;
+        self.samples.clear();
+        self
+    }
+
+    /// Adds a sample, samples that are not newer than the latest sample
+    /// or that never received a value are ignored
+    pub fn push(&mut self, value: SignalValue<T>) {
+        let timestamp = self.source.of(&value);
+        if timestamp <= 0.0 || self.capacity == 0 {
+            return;
+        }
+        if let Some(latest) = self.samples.back() {
+            if timestamp <= self.source.of(latest) {
+                return;
+            }
+        }
+        if self.samples.len() >= self.capacity {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(value);
+    }
+
+    /// The value at `timestamp` in seconds, interpolated between the samples on
+    /// either side of it with [`SPNValue::interpolate`].
+    ///
+    /// Returns `None` if `timestamp` is outside of the buffered samples.
+    pub fn sample_at(&self, timestamp: f64) -> Option<SignalValue<T>> {
+        let after = self
+            .samples
+            .partition_point(|sample| self.source.of(sample) < timestamp);
+        let next = self.samples.get(after)?;
+        let next_time = self.source.of(next);
+        if next_time == timestamp {
+            return Some(*next);
+        }
+        let prev = self.samples.get(after.checked_sub(1)?)?;
+        let prev_time = self.source.of(prev);
+        let t = (timestamp - prev_time) / (next_time - prev_time);
+        let lerp = |a: f64, b: f64| a + (b - a) * t;
+        Some(SignalValue {
+            value: prev.value.interpolate(next.value, t),
+            can_timestamp: lerp(prev.can_timestamp, next.can_timestamp),
+            software_timestamp: lerp(prev.software_timestamp, next.software_timestamp),
+            device_timestamp: lerp(prev.device_timestamp, next.device_timestamp),
+            status: prev.status.and(next.status),
+        })
+    }
+
+    /// Every sample with a timestamp in `start..=end` seconds, oldest first
+    pub fn range(&self, start: f64, end: f64) -> impl Iterator<Item = &SignalValue<T>> + '_ {
+        let first = self
+            .samples
+            .partition_point(|sample| self.source.of(sample) < start);
+        self.samples
+            .range(first..)
+            .take_while(move |sample| self.source.of(sample) <= end)
+    }
+
+    pub fn latest(&self) -> Option<&SignalValue<T>> {
+        self.samples.back()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &SignalValue<T>> {
+        self.samples.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.samples.clear();
+    }
+}
+
+/// A signal that records every value it is refreshed with, created with [`BaseSignal::with_history`].
+///
+/// Refreshing it directly, through [`super::wait_for_all`] or a [`super::group::SignalGroup`],
+/// or draining it when it wraps a queued signal all add to the history.
+pub struct HistorySignal<S, T: SPNValue> {
+    signal: S,
+    history: Mutex<SignalHistory<T>>,
+}
+impl<S: BaseSignal<T>, T: SPNValue> HistorySignal<S, T> {
+    pub(crate) fn new(signal: S, capacity: usize) -> Self {
+        Self {
+            signal,
+            history: Mutex::new(SignalHistory::new(capacity)),
+        }
+    }
+
+    /// Orders the history by `source` instead of the CAN timestamp, this clears the history
+    pub fn keyed_by(self, source: TimestampSource) -> Self {
+        Self {
+            signal: self.signal,
+            history: Mutex::new(self.history.into_inner().keyed_by(source)),
+        }
+    }
+
+    pub fn history(&self) -> MutexGuard<'_, SignalHistory<T>> {
+        self.history.lock()
+    }
+
+    /// See [`SignalHistory::sample_at`]
+    pub fn sample_at(&self, timestamp: f64) -> Option<SignalValue<T>> {
+        self.history.lock().sample_at(timestamp)
+    }
+
+    /// See [`SignalHistory::range`]
+    pub fn range(&self, start: f64, end: f64) -> Vec<SignalValue<T>> {
+        self.history.lock().range(start, end).copied().collect()
+    }
+
+    pub fn inner(&self) -> &S {
+        &self.signal
+    }
+}
+
+impl<S: BaseSignal<T>, T: SPNValue> BaseSignal<T> for HistorySignal<S, T> {
+    fn get_spn(&self) -> SPN {
+        self.signal.get_spn()
+    }
+
+    fn get_device_hash(&self) -> u32 {
+        self.signal.get_device_hash()
+    }
+
+    fn set_update_freq(&self, freq_hz: f64) -> Status<()> {
+        self.signal.set_update_freq(freq_hz)
+    }
+
+    /// Reads the wrapped signal and adds the value to the history,
+    /// so samples taken off a queued signal one at a time are recorded like [`drain`](QueuedStatusSignal::drain)
+    fn value(&self) -> Status<SignalValue<T>> {
+        let value = self.signal.value()?;
+        // a cached value that was already recorded is not newer and is ignored
+        self.history.lock().push(value);
+        Ok(value)
+    }
+}
+
+impl<S, T> RefreshableStatusSignal for HistorySignal<S, T>
+where
+    S: BaseSignal<T> + RefreshableStatusSignal,
+    T: SPNValue,
+{
+    fn refresh(&self) -> Status<()> {
+        self.signal.refresh()?;
+        self.history.lock().push(self.signal.value()?);
+        Ok(())
+    }
+
+    fn device_identifier(&self) -> &DeviceIdentifier {
+        self.signal.device_identifier()
+    }
+
+    fn spn(&self) -> SPN {
+        self.signal.spn()
+    }
+
+    fn update_cache(&self, value: Status<SignalValueRaw>) -> Status<()> {
+        self.signal.update_cache(value)?;
+        if let Ok(raw) = value {
+            self.history.lock().push(raw.try_cast()?);
+        }
+        Ok(())
+    }
+}
+
+impl<S, T> QueuedStatusSignal<T> for HistorySignal<S, T>
+where
+    S: QueuedStatusSignal<T>,
+    T: SPNValue,
+{
+    fn is_empty(&self) -> Status<bool> {
+        self.signal.is_empty()
+    }
+
+    /// Drains the queued samples, every sample is also added to the history
+    fn drain(&self) -> Vec<SignalValue<T>> {
+        let samples = self.signal.drain();
+        let mut history = self.history.lock();
+        for sample in &samples {
+            history.push(*sample);
+        }
+        samples
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signals::types::ForwardLimitValue;
+
+    fn sample_with<T: SPNValue>(
+        value: T,
+        can_timestamp: f64,
+        software_timestamp: f64,
+    ) -> SignalValue<T> {
+        SignalValue {
+            value,
+            can_timestamp,
+            software_timestamp,
+            ..Default::default()
+        }
+    }
+
+    fn sample<T: SPNValue>(value: T, can_timestamp: f64) -> SignalValue<T> {
+        sample_with(value, can_timestamp, can_timestamp + 0.5)
+    }
+
+    fn history(samples: &[(f64, f64)]) -> SignalHistory<f64> {
+        let mut history = SignalHistory::new(8);
+        for (value, timestamp) in samples {
+            history.push(sample(*value, *timestamp));
+        }
+        history
+    }
+
+    #[test]
+    fn push_keeps_newest_samples_in_order() {
+        let mut history = SignalHistory::new(2);
+        history.push(sample(1.0, 1.0));
+        history.push(sample(2.0, 2.0));
+        // not newer than the latest sample
+        history.push(sample(9.0, 2.0));
+        history.push(sample(9.0, 1.5));
+        // never received
+        history.push(sample_with(9.0, 0.0, 0.0));
+        assert_eq!(history.len(), 2);
+        history.push(sample(3.0, 3.0));
+        let values: Vec<f64> = history.iter().map(|sample| sample.value).collect();
+        assert_eq!(values, vec![2.0, 3.0]);
+    }
+
+    #[test]
+    fn push_falls_back_to_software_timestamp() {
+        let mut history = SignalHistory::new(4);
+        history.push(sample_with(1.0, 0.0, 0.5));
+        history.push(sample_with(2.0, 0.0, 1.0));
+        assert_eq!(history.len(), 2);
+        assert_eq!(
+            history.sample_at(0.75).map(|sample| sample.value),
+            Some(1.5)
+        );
+    }
+
+    #[test]
+    fn sample_at_exact_hit() {
+        let history = history(&[(1.0, 1.0), (2.0, 2.0), (4.0, 3.0)]);
+        let hit = history.sample_at(2.0).unwrap();
+        assert_eq!(hit.value, 2.0);
+        assert_eq!(hit.can_timestamp, 2.0);
+    }
+
+    #[test]
+    fn sample_at_interpolates_between_samples() {
+        let history = history(&[(1.0, 1.0), (2.0, 2.0), (4.0, 3.0)]);
+        let between = history.sample_at(2.25).unwrap();
+        assert_eq!(between.value, 2.5);
+        assert_eq!(between.can_timestamp, 2.25);
+        assert_eq!(between.software_timestamp, 2.75);
+    }
+
+    #[test]
+    fn sample_at_outside_of_range() {
+        let history = history(&[(1.0, 1.0), (2.0, 2.0)]);
+        assert!(history.sample_at(0.5).is_none());
+        assert!(history.sample_at(2.5).is_none());
+        assert!(SignalHistory::<f64>::new(4).sample_at(1.0).is_none());
+    }
+
+    #[test]
+    fn sample_at_picks_nearest_enum_sample() {
+        let mut history = SignalHistory::new(4);
+        history.push(sample(ForwardLimitValue::ClosedToGround, 1.0));
+        history.push(sample(ForwardLimitValue::Open, 2.0));
+        assert_eq!(
+            history.sample_at(1.25).unwrap().value,
+            ForwardLimitValue::ClosedToGround
+        );
+        assert_eq!(
+            history.sample_at(1.75).unwrap().value,
+            ForwardLimitValue::Open
+        );
+    }
+
+    #[test]
+    fn range_is_inclusive() {
+        let history = history(&[(1.0, 1.0), (2.0, 2.0), (3.0, 3.0), (4.0, 4.0)]);
+        let values: Vec<f64> = history.range(2.0, 3.0).map(|sample| sample.value).collect();
+        assert_eq!(values, vec![2.0, 3.0]);
+        assert_eq!(history.range(4.5, 5.0).count(), 0);
+    }
+}
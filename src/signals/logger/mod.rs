@@ -0,0 +1,484 @@
+use std::{
+    borrow::Cow,
+    ffi::CString,
+    os::raw::c_char,
+    path::PathBuf,
+    sync::atomic::{AtomicBool, AtomicU8, Ordering},
+};
+
+use frclib_core::units::{
+    angle::{Degree, Radian, Rotation},
+    angular_acceleration::RotationPerSecSqr,
+    angular_velocity::{DegreePerSec, RotationPerSec},
+    energy::{Amp, Volt},
+    temperature::Celsius,
+    time::{Millisecond, Second},
+};
+
+use crate::{
+    error::{StatusCode, StatusCodeType},
+    Status,
+};
+
+use super::{SPNValue, SignalValue};
+
+pub mod wpilog;
+
+/// Sets the destination for signal logging,
+/// restarting logger if the path changed.
+pub fn set_logger_path(path: PathBuf) -> Status<()> {
+    let path = path.to_str().ok_or(StatusCode::CouldNotSerialize)?;
+    let path = c_string(path)?;
+    unsafe {
+        ctre_phoenix6_sys::c_ctre_phoenix6_platform_set_logger_path(path.as_ptr()).to_result()
+    }
+}
+
+pub fn start() -> Status<()> {
+    unsafe { ctre_phoenix6_sys::c_ctre_phoenix6_platform_start_logger().to_result()? }
+    set_state(LoggerState::Running);
+    Ok(())
+}
+
+pub fn stop() -> Status<()> {
+    unsafe { ctre_phoenix6_sys::c_ctre_phoenix6_platform_stop_logger().to_result()? }
+    set_state(LoggerState::Stopped);
+    Ok(())
+}
+
+pub fn enable_auto_logging(enable: bool) -> Status<()> {
+    unsafe { ctre_phoenix6_sys::c_ctre_phoenix6_platform_enable_auto_logging(enable).to_result()? }
+    if enable && state() == LoggerState::Stopped {
+        set_state(LoggerState::Pending);
+    }
+    Ok(())
+}
+
+/// Whether the signal logger is running, as far as this process knows
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum LoggerState {
+    /// Stopped with [`stop`], writes fail with [`StatusCode::LoggerNotRunning`]
+    /// without reaching the platform
+    Stopped,
+    /// Started with [`start`] or a write has succeeded since
+    Running,
+    /// Not started or stopped by this process, the logger may be started by auto logging
+    /// when the robot enables, writes are attempted and the first one that succeeds moves
+    /// the logger to [`LoggerState::Running`]
+    Pending,
+}
+
+static STATE: AtomicU8 = AtomicU8::new(LoggerState::Pending as u8);
+static SESSION_ACTIVE: AtomicBool = AtomicBool::new(false);
+
+pub fn state() -> LoggerState {
+    match STATE.load(Ordering::Acquire) {
+        0 => LoggerState::Stopped,
+        1 => LoggerState::Running,
+        _ => LoggerState::Pending,
+    }
+}
+
+fn set_state(state: LoggerState) {
+    STATE.store(state as u8, Ordering::Release);
+}
+
+/// When a [`LoggerSession`] starts the signal logger
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum StartPolicy {
+    /// Start logging as soon as the session is started
+    #[default]
+    Immediately,
+    /// Leave starting to auto logging, which starts the logger when the robot enables,
+    /// this turns auto logging on regardless of [`LoggerSessionBuilder::auto_logging`]
+    OnEnable,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct LoggerSessionBuilder {
+    path: Option<PathBuf>,
+    auto_logging: bool,
+    start_policy: StartPolicy,
+}
+impl LoggerSessionBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The directory logs are written to, defaults to the platform default
+    pub fn path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// Whether the platform logs device signals automatically, defaults to false
+    pub fn auto_logging(mut self, enable: bool) -> Self {
+        self.auto_logging = enable;
+        self
+    }
+
+    /// When the logger is started, defaults to [`StartPolicy::Immediately`]
+    pub fn start_policy(mut self, start_policy: StartPolicy) -> Self {
+        self.start_policy = start_policy;
+        self
+    }
+
+    /// Configures the logger and starts it according to the start policy.
+    ///
+    /// Only one session can be active at a time,
+    /// starting another fails with [`StatusCode::LoggerSessionActive`]
+    pub fn start(self) -> Status<LoggerSession> {
+        if SESSION_ACTIVE.swap(true, Ordering::AcqRel) {
+            return Err(StatusCode::LoggerSessionActive);
+        }
+        // from here on dropping the session releases it and stops the logger on errors too
+        let session = LoggerSession { _private: () };
+        if let Some(path) = self.path {
+            set_logger_path(path)?;
+        }
+        match self.start_policy {
+            StartPolicy::Immediately => {
+                enable_auto_logging(self.auto_logging)?;
+                start()?;
+            }
+            StartPolicy::OnEnable => {
+                enable_auto_logging(true)?;
+                if state() == LoggerState::Stopped {
+                    set_state(LoggerState::Pending);
+                }
+            }
+        }
+        Ok(session)
+    }
+}
+
+/// An active configuration of the signal logger, the logger is stopped when this is dropped
+#[must_use = "the signal logger is stopped when the session is dropped"]
+pub struct LoggerSession {
+    _private: (),
+}
+impl LoggerSession {
+    pub fn builder() -> LoggerSessionBuilder {
+        LoggerSessionBuilder::new()
+    }
+
+    pub fn state(&self) -> LoggerState {
+        state()
+    }
+
+    /// See [`SignalLogger::write`]
+    pub fn write<L: Loggable + ?Sized>(&self, name: &str, value: &L) -> Status<()> {
+        SignalLogger::write(name, value)
+    }
+
+    /// See [`SignalLogger::write_with_units`]
+    pub fn write_with_units<L: Loggable + ?Sized>(
+        &self,
+        name: &str,
+        value: &L,
+        units: &str,
+    ) -> Status<()> {
+        SignalLogger::write_with_units(name, value, units)
+    }
+
+    /// Stops the logger and ends the session, same as dropping it but returns the outcome
+    pub fn stop(self) -> Status<()> {
+        let result = stop();
+        // the logger is already stopped, do not stop it again on drop
+        std::mem::forget(self);
+        SESSION_ACTIVE.store(false, Ordering::Release);
+        result
+    }
+}
+impl Drop for LoggerSession {
+    fn drop(&mut self) {
+        // the logger is stopped on a best effort basis, call `stop` to see the outcome
+        let _ = stop();
+        SESSION_ACTIVE.store(false, Ordering::Release);
+    }
+}
+
+const MAX_LOG_PACKET_SIZE: usize = 64;
+
+pub fn write_raw_to_log(name: String, data: &[u8]) -> Status<()> {
+    SignalLogger::write(&name, data)
+}
+
+/// A value in the form the signal logger stores it
+#[derive(Debug, Clone, PartialEq)]
+pub enum LogValue<'a> {
+    /// Raw bytes, at most 64 of them
+    Raw(&'a [u8]),
+    Boolean(bool),
+    Integer(i64, &'a str),
+    Float(f32, &'a str),
+    Double(f64, &'a str),
+    String(&'a str),
+    BooleanArray(&'a [bool]),
+    IntegerArray(Cow<'a, [i64]>, &'a str),
+    FloatArray(&'a [f32], &'a str),
+    DoubleArray(Cow<'a, [f64]>, &'a str),
+}
+impl<'a> LogValue<'a> {
+    /// Replaces the units of a numeric value, other values are returned unchanged
+    pub fn with_units(self, units: &'a str) -> Self {
+        match self {
+            LogValue::Integer(value, _) => LogValue::Integer(value, units),
+            LogValue::Float(value, _) => LogValue::Float(value, units),
+            LogValue::Double(value, _) => LogValue::Double(value, units),
+            LogValue::IntegerArray(values, _) => LogValue::IntegerArray(values, units),
+            LogValue::FloatArray(values, _) => LogValue::FloatArray(values, units),
+            LogValue::DoubleArray(values, _) => LogValue::DoubleArray(values, units),
+            other => other,
+        }
+    }
+}
+
+/// A type that can be written to the signal logger with [`SignalLogger::write`]
+pub trait Loggable {
+    fn to_log_value(&self) -> LogValue<'_>;
+}
+
+/// Writes custom values to the signal log alongside the device signals,
+/// the logger has to be started with [`start`] or a [`LoggerSession`] first.
+///
+/// Writes after the logger was stopped fail with [`StatusCode::LoggerNotRunning`]
+/// without reaching the platform, see [`LoggerState`]
+pub struct SignalLogger;
+impl SignalLogger {
+    /// Writes `value` under `name`, numeric values are logged with the units of their type
+    pub fn write<L: Loggable + ?Sized>(name: &str, value: &L) -> Status<()> {
+        write_value(name, value.to_log_value())
+    }
+
+    /// Writes `value` under `name` with `units` instead of the units of its type
+    pub fn write_with_units<L: Loggable + ?Sized>(
+        name: &str,
+        value: &L,
+        units: &str,
+    ) -> Status<()> {
+        write_value(name, value.to_log_value().with_units(units))
+    }
+}
+
+fn c_string(value: &str) -> Status<CString> {
+    CString::new(value).map_err(|_| StatusCode::CouldNotSerialize)
+}
+
+/// The element count of an array value, the platform takes at most 255 elements
+fn array_len<T>(values: &[T]) -> Status<u8> {
+    u8::try_from(values.len()).map_err(|_| StatusCode::InvalidSize)
+}
+
+fn write_value(name: &str, value: LogValue<'_>) -> Status<()> {
+    if state() == LoggerState::Stopped {
+        return Err(StatusCode::LoggerNotRunning);
+    }
+    let result = write_platform(name, value);
+    if result.is_ok() && state() == LoggerState::Pending {
+        set_state(LoggerState::Running);
+    }
+    result
+}
+
+fn write_platform(name: &str, value: LogValue<'_>) -> Status<()> {
+    use ctre_phoenix6_sys::*;
+
+    let name = c_string(name)?;
+    let name: *const c_char = name.as_ptr();
+    unsafe {
+        match value {
+            LogValue::Raw(data) => {
+                if data.len() > MAX_LOG_PACKET_SIZE {
+                    return Err(StatusCode::InvalidSize);
+                }
+                c_ctre_phoenix6_platform_write_raw(name, data.as_ptr(), data.len() as u8)
+            }
+            LogValue::Boolean(value) => c_ctre_phoenix6_platform_write_boolean(name, value),
+            LogValue::Integer(value, units) => {
+                c_ctre_phoenix6_platform_write_integer(name, value, c_string(units)?.as_ptr())
+            }
+            LogValue::Float(value, units) => {
+                c_ctre_phoenix6_platform_write_float(name, value, c_string(units)?.as_ptr())
+            }
+            LogValue::Double(value, units) => {
+                c_ctre_phoenix6_platform_write_double(name, value, c_string(units)?.as_ptr())
+            }
+            LogValue::String(value) => {
+                c_ctre_phoenix6_platform_write_string(name, c_string(value)?.as_ptr())
+            }
+            LogValue::BooleanArray(values) => c_ctre_phoenix6_platform_write_boolean_array(
+                name,
+                values.as_ptr(),
+                array_len(values)?,
+            ),
+            LogValue::IntegerArray(values, units) => c_ctre_phoenix6_platform_write_integer_array(
+                name,
+                values.as_ptr(),
+                array_len(&values)?,
+                c_string(units)?.as_ptr(),
+            ),
+            LogValue::FloatArray(values, units) => c_ctre_phoenix6_platform_write_float_array(
+                name,
+                values.as_ptr(),
+                array_len(values)?,
+                c_string(units)?.as_ptr(),
+            ),
+            LogValue::DoubleArray(values, units) => c_ctre_phoenix6_platform_write_double_array(
+                name,
+                values.as_ptr(),
+                array_len(&values)?,
+                c_string(units)?.as_ptr(),
+            ),
+        }
+        .to_result()
+    }
+}
+
+impl<L: Loggable + ?Sized> Loggable for &L {
+    fn to_log_value(&self) -> LogValue<'_> {
+        (**self).to_log_value()
+    }
+}
+
+impl<T> Loggable for Vec<T>
+where
+    [T]: Loggable,
+{
+    fn to_log_value(&self) -> LogValue<'_> {
+        self.as_slice().to_log_value()
+    }
+}
+
+impl<T, const N: usize> Loggable for [T; N]
+where
+    [T]: Loggable,
+{
+    fn to_log_value(&self) -> LogValue<'_> {
+        self.as_slice().to_log_value()
+    }
+}
+
+impl Loggable for bool {
+    fn to_log_value(&self) -> LogValue<'_> {
+        LogValue::Boolean(*self)
+    }
+}
+
+impl Loggable for f32 {
+    fn to_log_value(&self) -> LogValue<'_> {
+        LogValue::Float(*self, "")
+    }
+}
+
+impl Loggable for f64 {
+    fn to_log_value(&self) -> LogValue<'_> {
+        LogValue::Double(*self, "")
+    }
+}
+
+impl Loggable for str {
+    fn to_log_value(&self) -> LogValue<'_> {
+        LogValue::String(self)
+    }
+}
+
+impl Loggable for String {
+    fn to_log_value(&self) -> LogValue<'_> {
+        LogValue::String(self)
+    }
+}
+
+impl Loggable for [u8] {
+    fn to_log_value(&self) -> LogValue<'_> {
+        LogValue::Raw(self)
+    }
+}
+
+impl Loggable for [bool] {
+    fn to_log_value(&self) -> LogValue<'_> {
+        LogValue::BooleanArray(self)
+    }
+}
+
+impl Loggable for [i64] {
+    fn to_log_value(&self) -> LogValue<'_> {
+        LogValue::IntegerArray(Cow::Borrowed(self), "")
+    }
+}
+
+impl Loggable for [f32] {
+    fn to_log_value(&self) -> LogValue<'_> {
+        LogValue::FloatArray(self, "")
+    }
+}
+
+impl Loggable for [f64] {
+    fn to_log_value(&self) -> LogValue<'_> {
+        LogValue::DoubleArray(Cow::Borrowed(self), "")
+    }
+}
+
+macro_rules! loggable_integer {
+    ($($int:ty),*) => {
+        $(
+            impl Loggable for $int {
+                fn to_log_value(&self) -> LogValue<'_> {
+                    LogValue::Integer(i64::from(*self), "")
+                }
+            }
+        )*
+    };
+}
+
+loggable_integer!(i8, i16, i32, i64, u8, u16, u32);
+
+macro_rules! loggable_integer_array {
+    ($($int:ty),*) => {
+        $(
+            impl Loggable for [$int] {
+                fn to_log_value(&self) -> LogValue<'_> {
+                    LogValue::IntegerArray(self.iter().map(|value| i64::from(*value)).collect(), "")
+                }
+            }
+        )*
+    };
+}
+
+// `[u8]` is logged as raw bytes
+loggable_integer_array!(i8, i16, i32, u16, u32);
+
+macro_rules! loggable_unit {
+    ($unit:ty, $units:literal) => {
+        impl Loggable for $unit {
+            fn to_log_value(&self) -> LogValue<'_> {
+                LogValue::Double(self.value(), $units)
+            }
+        }
+        impl Loggable for [$unit] {
+            fn to_log_value(&self) -> LogValue<'_> {
+                LogValue::DoubleArray(self.iter().map(|value| value.value()).collect(), $units)
+            }
+        }
+    };
+}
+
+loggable_unit!(Rotation, "rotations");
+loggable_unit!(Degree, "degrees");
+loggable_unit!(Radian, "radians");
+loggable_unit!(RotationPerSec, "rotations per second");
+loggable_unit!(DegreePerSec, "degrees per second");
+loggable_unit!(RotationPerSecSqr, "rotations per second²");
+loggable_unit!(Celsius, "℃");
+loggable_unit!(Amp, "A");
+loggable_unit!(Volt, "V");
+loggable_unit!(Second, "s");
+loggable_unit!(Millisecond, "ms");
+
+/// Logs the value of the signal, the timestamps are not logged
+impl<T: SPNValue + Loggable> Loggable for SignalValue<T> {
+    fn to_log_value(&self) -> LogValue<'_> {
+        self.value.to_log_value()
+    }
+}
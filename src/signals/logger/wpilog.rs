@@ -0,0 +1,399 @@
+//! A pure Rust writer for the WPILib data log format (`.wpilog`),
+//! for simulation and tests where the native signal logger is not available.
+//!
+//! The logs can be opened in AdvantageScope and read with the WPILib data log tools.
+
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+use frclib_core::units::time::Time;
+use once_cell::sync::Lazy;
+use parking_lot::{Mutex, RwLock};
+
+use crate::{
+    devices::DeviceIdentifier,
+    signals::{SPNValue, SignalValue},
+    spn::SPN,
+};
+
+use super::{LogValue, Loggable};
+
+const MAGIC: &[u8] = b"WPILOG";
+const VERSION: u16 = 0x0100;
+const CONTROL_START: u8 = 0;
+
+static RECORDING: AtomicBool = AtomicBool::new(false);
+static RECORDER: Lazy<RwLock<Option<WpiLogWriter>>> = Lazy::new(|| RwLock::new(None));
+
+struct Entry {
+    id: u32,
+    type_name: &'static str,
+}
+
+struct WriterState {
+    out: Box<dyn Write + Send>,
+    entries: HashMap<String, Entry>,
+    /// Entry ids of recorded device signals by device hash and spn
+    signal_entries: HashMap<(u32, u32), u32>,
+    next_id: u32,
+}
+impl WriterState {
+    /// Writes the start record of an entry the first time `name` is written
+    fn entry(&mut self, name: &str, type_name: &'static str, units: &str) -> io::Result<u32> {
+        if let Some(entry) = self.entries.get(name) {
+            if entry.type_name != type_name {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!(
+                        "{name} is a {} entry and can not be written as {type_name}",
+                        entry.type_name
+                    ),
+                ));
+            }
+            return Ok(entry.id);
+        }
+        let id = self.next_id;
+        self.next_id += 1;
+        let metadata = if units.is_empty() {
+            String::new()
+        } else {
+            format!("{{\"units\":\"{}\"}}", escape_json(units))
+        };
+        let mut payload = vec![CONTROL_START];
+        payload.extend_from_slice(&id.to_le_bytes());
+        for field in [name, type_name, &metadata] {
+            payload.extend_from_slice(&(field.len() as u32).to_le_bytes());
+            payload.extend_from_slice(field.as_bytes());
+        }
+        self.record(0, 0, &payload)?;
+        self.entries
+            .insert(name.to_owned(), Entry { id, type_name });
+        Ok(id)
+    }
+
+    fn record(&mut self, id: u32, timestamp_us: u64, payload: &[u8]) -> io::Result<()> {
+        let id_len = int_len(u64::from(id), 4);
+        let size_len = int_len(payload.len() as u64, 4);
+        let timestamp_len = int_len(timestamp_us, 8);
+        let header =
+            (id_len - 1) as u8 | ((size_len - 1) as u8) << 2 | ((timestamp_len - 1) as u8) << 4;
+        self.out.write_all(&[header])?;
+        self.out.write_all(&id.to_le_bytes()[..id_len])?;
+        self.out
+            .write_all(&(payload.len() as u32).to_le_bytes()[..size_len])?;
+        self.out
+            .write_all(&timestamp_us.to_le_bytes()[..timestamp_len])?;
+        self.out.write_all(payload)
+    }
+
+    fn write(&mut self, name: &str, value: LogValue<'_>, timestamp_us: u64) -> io::Result<()> {
+        let (type_name, units, payload) = encode(value);
+        let id = self.entry(name, type_name, units)?;
+        self.record(id, timestamp_us, &payload)
+    }
+}
+
+/// The number of bytes needed to store `value`, at least one and at most `max`
+fn int_len(value: u64, max: usize) -> usize {
+    let bytes = (64 - value.leading_zeros() as usize).div_ceil(8);
+    bytes.clamp(1, max)
+}
+
+fn escape_json(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// The entry type, units and record payload of a value
+fn encode(value: LogValue<'_>) -> (&'static str, &str, Vec<u8>) {
+    match value {
+        LogValue::Raw(data) => ("raw", "", data.to_vec()),
+        LogValue::Boolean(value) => ("boolean", "", vec![u8::from(value)]),
+        LogValue::Integer(value, units) => ("int64", units, value.to_le_bytes().to_vec()),
+        LogValue::Float(value, units) => ("float", units, value.to_le_bytes().to_vec()),
+        LogValue::Double(value, units) => ("double", units, value.to_le_bytes().to_vec()),
+        LogValue::String(value) => ("string", "", value.as_bytes().to_vec()),
+        LogValue::BooleanArray(values) => (
+            "boolean[]",
+            "",
+            values.iter().map(|value| u8::from(*value)).collect(),
+        ),
+        LogValue::IntegerArray(values, units) => (
+            "int64[]",
+            units,
+            values
+                .iter()
+                .flat_map(|value| value.to_le_bytes())
+                .collect(),
+        ),
+        LogValue::FloatArray(values, units) => (
+            "float[]",
+            units,
+            values
+                .iter()
+                .flat_map(|value| value.to_le_bytes())
+                .collect(),
+        ),
+        LogValue::DoubleArray(values, units) => (
+            "double[]",
+            units,
+            values
+                .iter()
+                .flat_map(|value| value.to_le_bytes())
+                .collect(),
+        ),
+    }
+}
+
+fn to_micros(timestamp: impl Time) -> u64 {
+    (timestamp.to_seconds().value() * 1_000_000.0).max(0.0) as u64
+}
+
+/// Writes typed entries to a `.wpilog` file, the same values the native logger takes
+/// through [`super::SignalLogger::write`].
+///
+/// Clones share the same output, buffered writes reach the file when [`flush`](Self::flush)
+/// or [`stop_recording_signals`](Self::stop_recording_signals) is called, or when the
+/// last clone is dropped.
+#[derive(Clone)]
+pub struct WpiLogWriter {
+    state: Arc<Mutex<WriterState>>,
+}
+impl WpiLogWriter {
+    /// Creates or truncates the file at `path` and writes the log header
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::new(BufWriter::new(File::create(path)?))
+    }
+
+    /// Writes the log header to `out`, every entry is written to it after
+    pub fn new(out: impl Write + Send + 'static) -> io::Result<Self> {
+        Self::with_extra_header(out, "")
+    }
+
+    /// Like [`new`](Self::new) but with a free form string stored in the log header
+    pub fn with_extra_header(
+        mut out: impl Write + Send + 'static,
+        extra: &str,
+    ) -> io::Result<Self> {
+        out.write_all(MAGIC)?;
+        out.write_all(&VERSION.to_le_bytes())?;
+        out.write_all(&(extra.len() as u32).to_le_bytes())?;
+        out.write_all(extra.as_bytes())?;
+        Ok(Self {
+            state: Arc::new(Mutex::new(WriterState {
+                out: Box::new(out),
+                entries: HashMap::new(),
+                signal_entries: HashMap::new(),
+                // entry 0 is reserved for control records
+                next_id: 1,
+            })),
+        })
+    }
+
+    /// Writes `value` under `name` timestamped with [`crate::signals::current_time`],
+    /// the timebase of the signal timestamps
+    pub fn write<L: Loggable + ?Sized>(&self, name: &str, value: &L) -> io::Result<()> {
+        self.write_at(name, value, crate::signals::current_time())
+    }
+
+    /// Writes `value` under `name` with an explicit timestamp,
+    /// for tests where the native timebase is not available
+    pub fn write_at<L: Loggable + ?Sized>(
+        &self,
+        name: &str,
+        value: &L,
+        timestamp: impl Time,
+    ) -> io::Result<()> {
+        self.state
+            .lock()
+            .write(name, value.to_log_value(), to_micros(timestamp))
+    }
+
+    /// Writes `value` under `name` with `units` instead of the units of its type,
+    /// units are stored in the entry metadata when the entry is first written
+    pub fn write_with_units<L: Loggable + ?Sized>(
+        &self,
+        name: &str,
+        value: &L,
+        units: &str,
+    ) -> io::Result<()> {
+        let timestamp = to_micros(crate::signals::current_time());
+        self.state
+            .lock()
+            .write(name, value.to_log_value().with_units(units), timestamp)
+    }
+
+    /// Writes `value` under `name` timestamped with [`SignalValue::timestamp`],
+    /// the CAN timestamp when the device provided one
+    pub fn write_signal<T: Loggable + SPNValue>(
+        &self,
+        name: &str,
+        value: &SignalValue<T>,
+    ) -> io::Result<()> {
+        self.state.lock().write(
+            name,
+            value.to_log_value(),
+            (value.timestamp() * 1_000_000.0).max(0.0) as u64,
+        )
+    }
+
+    pub fn flush(&self) -> io::Result<()> {
+        self.state.lock().out.flush()
+    }
+
+    /// Records every signal value read from a device from now on, replacing any writer
+    /// that was recording before.
+    ///
+    /// Values are written as doubles named `device/{model}/{id}/{signal}`
+    /// and timestamped with the CAN timestamp of the value.
+    pub fn record_signals(&self) {
+        *RECORDER.write() = Some(self.clone());
+        RECORDING.store(true, Ordering::Release);
+    }
+
+    /// Stops recording device signals if this writer is recording them
+    /// and flushes every value written so far
+    pub fn stop_recording_signals(&self) -> io::Result<()> {
+        {
+            let mut recorder = RECORDER.write();
+            if let Some(current) = recorder.as_ref() {
+                if Arc::ptr_eq(&current.state, &self.state) {
+                    *recorder = None;
+                    RECORDING.store(false, Ordering::Release);
+                }
+            }
+        }
+        self.flush()
+    }
+
+    fn record_signal(&self, hash: u32, spn: u32, value: f64, timestamp: f64) -> io::Result<()> {
+        let mut state = self.state.lock();
+        let id = match state.signal_entries.get(&(hash, spn)) {
+            Some(id) => *id,
+            None => {
+                // a device that was closed can not be named anymore, its values are dropped
+                let Some(device) = DeviceIdentifier::from_hash(hash) else {
+                    return Ok(());
+                };
+                let signal = SPN::try_from(spn as i32)
+                    .map(|spn| format!("{spn:?}"))
+                    .unwrap_or_else(|_| spn.to_string());
+                let model = device.model.to_string();
+                let name = format!("device/{model}/{}/{signal}", device.index.index());
+                let id = state.entry(&name, "double", "")?;
+                state.signal_entries.insert((hash, spn), id);
+                id
+            }
+        };
+        state.record(
+            id,
+            (timestamp * 1_000_000.0).max(0.0) as u64,
+            &value.to_le_bytes(),
+        )
+    }
+}
+
+/// Records a value read from a device if a writer is recording signals,
+/// the value is timestamped with its CAN timestamp when the device provided one
+pub(crate) fn record(hash: u32, spn: u32, value: f64, can_timestamp: f64, software_timestamp: f64) {
+    if !RECORDING.load(Ordering::Acquire) {
+        return;
+    }
+    let timestamp = if can_timestamp > 0.0 {
+        can_timestamp
+    } else {
+        software_timestamp
+    };
+    // a value that was never received has nothing to record
+    if timestamp <= 0.0 {
+        return;
+    }
+    if let Some(writer) = RECORDER.read().as_ref() {
+        // recording must not fail the read, a failed write only loses the sample
+        let _ = writer.record_signal(hash, spn, value, timestamp);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use frclib_core::units::time::Second;
+
+    use super::*;
+
+    /// An output the test can read back after the writer took ownership of it
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn writer() -> (WpiLogWriter, SharedBuffer) {
+        let buffer = SharedBuffer::default();
+        (WpiLogWriter::new(buffer.clone()).unwrap(), buffer)
+    }
+
+    #[test]
+    fn header() {
+        let (_writer, buffer) = writer();
+        assert_eq!(*buffer.0.lock(), b"WPILOG\x00\x01\x00\x00\x00\x00");
+    }
+
+    #[test]
+    fn start_and_data_records() {
+        let (writer, buffer) = writer();
+        let header_len = buffer.0.lock().len();
+        writer.write_at("flag", &true, Second::new(1.0)).unwrap();
+
+        // 1 byte id, size and timestamp for entry 0, the control entry, at timestamp 0
+        let mut start = vec![0x00, 0x00, 28, 0x00];
+        start.push(CONTROL_START);
+        start.extend_from_slice(&1u32.to_le_bytes());
+        start.extend_from_slice(&4u32.to_le_bytes());
+        start.extend_from_slice(b"flag");
+        start.extend_from_slice(&7u32.to_le_bytes());
+        start.extend_from_slice(b"boolean");
+        start.extend_from_slice(&0u32.to_le_bytes());
+
+        // 1 byte id, 1 byte size, 3 byte timestamp for 1_000_000us
+        let mut data = vec![0x20, 0x01, 0x01];
+        data.extend_from_slice(&1_000_000u32.to_le_bytes()[..3]);
+        data.push(0x01);
+
+        let written = buffer.0.lock()[header_len..].to_vec();
+        assert_eq!(written, [start, data].concat());
+    }
+
+    #[test]
+    fn entries_are_started_once() {
+        let (writer, buffer) = writer();
+        writer.write_at("value", &1.0, Second::new(0.0)).unwrap();
+        let after_first = buffer.0.lock().len();
+        writer.write_at("value", &2.0, Second::new(0.0)).unwrap();
+        // header byte, id, size, timestamp and the double
+        assert_eq!(buffer.0.lock().len() - after_first, 1 + 1 + 1 + 1 + 8);
+        assert!(writer.write_at("value", &true, Second::new(0.0)).is_err());
+    }
+}
@@ -1,10 +1,24 @@
-use crate::{spn::SPN, Status};
+use frclib_core::units::{
+    angle::Rotation,
+    angular_velocity::RotationPerSec,
+    time::{Second, Time},
+};
+
+use crate::{devices::DeviceIdentifier, spn::SPN, Status};
 
 use self::{__sealed::Sealed, native::SignalSpecifier};
 
+#[cfg(feature = "async")]
+pub mod asynchronous;
+pub mod faults;
+pub mod group;
+pub mod history;
 pub mod logger;
 pub(crate) mod native;
+pub mod odometry;
 pub(crate) mod queue_thread;
+pub mod queued;
+pub mod triggers;
 pub mod types;
 
 pub(crate) type SignalSource = SignalSpecifier;
@@ -18,6 +32,100 @@ pub trait BaseSignal<T: SPNValue> {
 
     fn value(&self) -> Status<SignalValue<T>>;
 
+    /// Returns true if the last update failed or the cached value is older than `max_age`,
+    /// see [`SignalValue::is_stale`]
+    fn is_stale(&self, max_age: impl Time) -> Status<bool>
+    where
+        Self: Sized,
+    {
+        Ok(self.value()?.is_stale(max_age))
+    }
+
+    /// Sets the signal to update at `freq_hz` and polls it in the background,
+    /// up to `capacity` samples are queued until they are drained from the returned signal,
+    /// the oldest sample is dropped when the queue is full
+    fn queued(&self, freq_hz: f64, capacity: usize) -> Status<queued::QueuedSignal<T>>
+    where
+        Self: Sized,
+    {
+        if !freq_hz.is_finite() || freq_hz <= 0.0 || capacity == 0 {
+            return Err(crate::error::StatusCode::InvalidParamValue);
+        }
+        self.set_update_freq(freq_hz)?;
+        queued::QueuedSignal::new(self.get_device_hash(), self.get_spn(), freq_hz, capacity)
+    }
+
+    /// Wraps the signal so every value it is refreshed with is kept in a
+    /// [`history::SignalHistory`] of up to `capacity` samples
+    fn with_history(self, capacity: usize) -> history::HistorySignal<Self, T>
+    where
+        Self: Sized,
+    {
+        history::HistorySignal::new(self, capacity)
+    }
+
+    /// Runs `callback` on a background worker every time the value changes,
+    /// see [`triggers`] for where callbacks run
+    fn on_change(
+        &self,
+        options: triggers::TriggerOptions,
+        callback: impl FnMut(SignalValue<T>) + Send + 'static,
+    ) -> Status<triggers::Subscription>
+    where
+        Self: Sized,
+        T: PartialEq + Send + 'static,
+    {
+        triggers::on_change(self.get_device_hash(), self.get_spn(), options, callback)
+    }
+
+    /// Runs `callback` on a background worker when the value crosses `threshold` in the
+    /// direction of `crossing`, it fires again only after the value comes back past the
+    /// threshold by the [hysteresis](triggers::TriggerOptions::hysteresis)
+    fn on_threshold(
+        &self,
+        crossing: triggers::Crossing,
+        threshold: f64,
+        options: triggers::TriggerOptions,
+        callback: impl FnMut(SignalValue<T>) + Send + 'static,
+    ) -> Status<triggers::Subscription>
+    where
+        Self: Sized,
+        T: Send + 'static,
+    {
+        triggers::on_threshold(
+            self.get_device_hash(),
+            self.get_spn(),
+            crossing,
+            threshold,
+            options,
+            callback,
+        )
+    }
+
+    /// Runs `callback` on a background worker for every fault that becomes active,
+    /// for the `faults` and `sticky_faults` signals of a device
+    fn on_fault_raised(
+        &self,
+        options: triggers::TriggerOptions,
+        callback: impl FnMut(faults::Fault) + Send + 'static,
+    ) -> Status<triggers::Subscription>
+    where
+        Self: Sized,
+        T: Into<faults::FaultSet> + Send + 'static,
+    {
+        triggers::on_fault_raised::<T>(self.get_device_hash(), self.get_spn(), options, callback)
+    }
+
+    /// Like [`queued`](Self::queued) but every sample is yielded by a [`futures_core::Stream`]
+    #[cfg(feature = "async")]
+    fn stream(&self, freq_hz: f64, capacity: usize) -> Status<asynchronous::SignalStream<T>>
+    where
+        Self: Sized,
+    {
+        self.queued(freq_hz, capacity)
+            .map(asynchronous::SignalStream::new)
+    }
+
     fn same_source_as(&self, other: &dyn BaseSignal<T>) -> bool {
         self.get_device_hash() == other.get_device_hash() && self.get_spn() == other.get_spn()
     }
@@ -25,42 +133,330 @@ pub trait BaseSignal<T: SPNValue> {
 
 pub trait QueuedStatusSignal<T: SPNValue>: BaseSignal<T> {
     fn is_empty(&self) -> Status<bool>;
+
+    /// Returns every sample received since the last drain, oldest first
+    fn drain(&self) -> Vec<SignalValue<T>>;
 }
 
-pub trait RefreshableStatusSignal<T: SPNValue>: BaseSignal<T> {
+/// A signal whose value can be refreshed from the device,
+/// this is object safe so signals of different value types can be refreshed together
+pub trait RefreshableStatusSignal {
     fn refresh(&self) -> Status<()>;
+
+    fn device_identifier(&self) -> &DeviceIdentifier;
+
+    fn spn(&self) -> SPN;
+
+    /// Stores the outcome of a request in the device cache, a failed request
+    /// marks the cached value with the error, does nothing for cold signals
+    #[doc(hidden)]
+    fn update_cache(&self, value: Status<SignalValueRaw>) -> Status<()>;
+}
+
+/// Waits up to `timeout` for every signal to receive new data and updates the device caches.
+///
+/// Signals on the same CAN bus are requested together in a single call so they are sampled together.
+/// The returned statuses are in the same order as `signals`.
+pub fn wait_for_all(
+    timeout: impl Time,
+    signals: &[&dyn RefreshableStatusSignal],
+) -> Vec<Status<()>> {
+    request_all(timeout.to_seconds().value(), signals)
+}
+
+/// Updates the device caches with the latest value of every signal without waiting for new data.
+///
+/// Signals on the same CAN bus are requested together in a single call.
+/// The returned statuses are in the same order as `signals`.
+pub fn refresh_all(signals: &[&dyn RefreshableStatusSignal]) -> Vec<Status<()>> {
+    request_all(0.0, signals)
+}
+
+fn request_all(timeout: f64, signals: &[&dyn RefreshableStatusSignal]) -> Vec<Status<()>> {
+    let requests = bus_requests(timeout, signals);
+    let responses = execute_bus_requests(&requests);
+    apply_bus_responses(signals, &requests, responses)
+}
+
+/// The signals of a batched request on a single CAN bus,
+/// `indices` are the positions of the signals in the callers list
+pub(crate) struct BusRequest {
+    meta: native::SignalMeta,
+    indices: Vec<usize>,
+    specifiers: Vec<SignalSpecifier>,
+}
+
+pub(crate) fn bus_requests<S: RefreshableStatusSignal + ?Sized>(
+    timeout: f64,
+    signals: &[&S],
+) -> Vec<BusRequest> {
+    let mut requests: Vec<BusRequest> = Vec::new();
+    for (i, signal) in signals.iter().enumerate() {
+        let identifier = signal.device_identifier();
+        let specifier = SignalSpecifier {
+            hash: identifier.hash.0,
+            spn: signal.spn(),
+        };
+        match requests
+            .iter_mut()
+            .find(|request| request.meta.can_bus == identifier.canbus)
+        {
+            Some(request) => {
+                request.indices.push(i);
+                request.specifiers.push(specifier);
+            }
+            None => requests.push(BusRequest {
+                meta: native::SignalMeta {
+                    can_bus: identifier.canbus.clone(),
+                    timeout,
+                },
+                indices: vec![i],
+                specifiers: vec![specifier],
+            }),
+        }
+    }
+    requests
+}
+
+/// Makes one blocking request per bus
+pub(crate) fn execute_bus_requests(requests: &[BusRequest]) -> Vec<Status<Vec<SignalValueRaw>>> {
+    requests
+        .iter()
+        .map(|request| {
+            native::request_signal_values_dynamic(request.meta.clone(), &request.specifiers)
+        })
+        .collect()
+}
+
+/// Stores every response in the device caches,
+/// the returned statuses are in the same order as `signals`
+pub(crate) fn apply_bus_responses<S: RefreshableStatusSignal + ?Sized>(
+    signals: &[&S],
+    requests: &[BusRequest],
+    responses: Vec<Status<Vec<SignalValueRaw>>>,
+) -> Vec<Status<()>> {
+    let mut results: Vec<Status<()>> = vec![Ok(()); signals.len()];
+    for (request, response) in requests.iter().zip(responses) {
+        match response {
+            Ok(values) => {
+                for (&i, value) in request.indices.iter().zip(values) {
+                    results[i] = signals[i].update_cache(Ok(value));
+                }
+            }
+            Err(err) => {
+                for &i in &request.indices {
+                    results[i] = signals[i].update_cache(Err(err)).and(Err(err));
+                }
+            }
+        }
+    }
+    results
 }
 
 use crate::__sealed;
 
 pub trait SPNValue: Sealed + Sized + Default + Copy {
     fn try_from_f64(value: f64) -> Status<Self>;
+
+    /// The value as the device reports it, the inverse of [`try_from_f64`](Self::try_from_f64)
+    fn to_f64(self) -> f64;
+
+    /// The value a fraction `t` of the way from `self` to `other`,
+    /// values that can not be blended like enums and bools pick the nearest of the two
+    fn interpolate(self, other: Self, t: f64) -> Self {
+        if t < 0.5 {
+            self
+        } else {
+            other
+        }
+    }
 }
 
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Copy)]
 pub struct SignalValue<T: SPNValue> {
     pub value: T,
     pub can_timestamp: f64,
     pub software_timestamp: f64,
     pub device_timestamp: f64,
+    /// The outcome of the last attempt to update this value,
+    /// if it is an error `value` is the last good sample
+    pub status: Status<()>,
+}
+impl<T: SPNValue> Default for SignalValue<T> {
+    fn default() -> Self {
+        Self {
+            value: T::default(),
+            can_timestamp: 0.0,
+            software_timestamp: 0.0,
+            device_timestamp: 0.0,
+            status: Ok(()),
+        }
+    }
 }
 pub type SignalValueRaw = native::SignalValueResponse;
+/// A value that does not fit `T` is replaced by the default of `T` and the error
+/// is kept in `status`, use [`SignalValueRaw::try_cast`] to fail instead
 impl<T: SPNValue> From<SignalValueRaw> for SignalValue<T> {
     fn from(raw: SignalValueRaw) -> Self {
+        let (value, status) = match T::try_from_f64(raw.value) {
+            Ok(value) => (value, Ok(())),
+            Err(err) => (T::default(), Err(err)),
+        };
         Self {
-            value: T::try_from_f64(raw.value).unwrap(),
+            value,
             can_timestamp: raw.can_timestamp,
             software_timestamp: raw.software_timestamp,
             device_timestamp: raw.device_timestamp,
+            status,
+        }
+    }
+}
+impl<T: SPNValue> From<CachedValue> for SignalValue<T> {
+    fn from(cached: CachedValue) -> Self {
+        let value = Self::from(cached.raw);
+        Self {
+            // a failed update is reported before a value that does not fit `T`
+            status: cached.status.and(value.status),
+            ..value
+        }
+    }
+}
+impl<T: SPNValue> SignalValue<T> {
+    /// The time the value was sampled in seconds, this is the CAN timestamp
+    /// if the device provided one and the software timestamp otherwise.
+    ///
+    /// Returns `0.0` if the signal has never received a value
+    pub fn timestamp(&self) -> f64 {
+        if self.can_timestamp > 0.0 {
+            self.can_timestamp
+        } else {
+            self.software_timestamp
+        }
+    }
+
+    /// How long ago the value was sampled
+    pub fn age(&self) -> Second {
+        Second::new(current_time().value() - self.timestamp())
+    }
+
+    /// Returns true if the last update failed or the value is older than `max_age`,
+    /// a value that has never been received is always stale
+    pub fn is_stale(&self, max_age: impl Time) -> bool {
+        self.status.is_err()
+            || self.timestamp() <= 0.0
+            || self.age().value() > max_age.to_seconds().value()
+    }
+}
+
+/// A device cache entry, the last good sample of a signal
+/// and the outcome of the last attempt to update it.
+///
+/// A failed update keeps the last good sample so the time of the last
+/// successful update is still the sample timestamp.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CachedValue {
+    pub(crate) raw: SignalValueRaw,
+    pub(crate) status: Status<()>,
+    /// Set when the device value was changed by a write, the cached sample
+    /// is from before the write until an update succeeds
+    pub(crate) invalidated: bool,
+}
+impl Default for CachedValue {
+    fn default() -> Self {
+        Self {
+            raw: SignalValueRaw::default(),
+            status: Ok(()),
+            invalidated: false,
+        }
+    }
+}
+impl CachedValue {
+    /// Stores the outcome of a request, returning the error if it failed
+    pub(crate) fn update(&mut self, value: Status<SignalValueRaw>) -> Status<()> {
+        match value {
+            Ok(raw) => {
+                self.raw = raw;
+                self.status = Ok(());
+                self.invalidated = false;
+            }
+            Err(err) => self.status = Err(err),
+        }
+        self.status
+    }
+
+    /// Marks the cached sample as out of date, reads fail until the next successful update
+    pub(crate) fn invalidate(&mut self) {
+        self.invalidated = true;
+        self.status = Err(crate::error::StatusCode::SigNotUpdated);
+    }
+
+    /// The cached value, fails with [`StatusCode::SigNotUpdated`](crate::error::StatusCode::SigNotUpdated)
+    /// if it was invalidated and has not been updated since
+    pub(crate) fn read<T: SPNValue>(&self) -> Status<SignalValue<T>> {
+        if self.invalidated {
+            return Err(crate::error::StatusCode::SigNotUpdated);
         }
+        Ok(SignalValue::from(*self))
     }
 }
 
+/// The current time in seconds on the same clock as [`SignalValue`] timestamps
+pub fn current_time() -> Second {
+    Second::new(unsafe { ctre_phoenix6_sys::c_ctre_phoenix6_get_current_time_seconds() })
+}
+
+/// Extrapolates `position` to the current time using `velocity`,
+/// see [`latency_compensate_to`]
+pub fn latency_compensate<P, V>(position: SignalValue<P>, velocity: SignalValue<V>) -> P
+where
+    P: SPNValue + Into<Rotation> + From<Rotation>,
+    V: SPNValue + Into<RotationPerSec>,
+{
+    latency_compensate_to(position, velocity, current_time())
+}
+
+/// Extrapolates `position` to `now` using `velocity`, this compensates for the
+/// time it took the value to arrive so a reading like a CANcoder `position`
+/// with its `velocity` or a Pigeon2 `yaw` with `angular_velocity_z_world`
+/// reflects where the mechanism is at `now`.
+///
+/// The units of `position` and `velocity` do not need to match, both are
+/// converted through [`Rotation`] and [`RotationPerSec`].
+/// If `position` has never received a value it is returned unchanged.
+pub fn latency_compensate_to<P, V>(
+    position: SignalValue<P>,
+    velocity: SignalValue<V>,
+    now: impl Time,
+) -> P
+where
+    P: SPNValue + Into<Rotation> + From<Rotation>,
+    V: SPNValue + Into<RotationPerSec>,
+{
+    let sampled_at = position.timestamp();
+    if sampled_at <= 0.0 {
+        return position.value;
+    }
+    let latency = now.to_seconds().value() - sampled_at;
+    let rotations: Rotation = position.value.into();
+    let velocity: RotationPerSec = velocity.value.into();
+    P::from(Rotation::new(
+        rotations.value() + velocity.value() * latency,
+    ))
+}
+
 impl __sealed::Sealed for f64 {}
 impl SPNValue for f64 {
     fn try_from_f64(value: f64) -> Status<Self> {
         Ok(value)
     }
+
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    fn interpolate(self, other: Self, t: f64) -> Self {
+        self + (other - self) * t
+    }
 }
 
 impl __sealed::Sealed for i32 {}
@@ -68,6 +464,10 @@ impl SPNValue for i32 {
     fn try_from_f64(value: f64) -> Status<Self> {
         Ok(value as i32)
     }
+
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
 }
 
 impl __sealed::Sealed for u32 {}
@@ -75,6 +475,10 @@ impl SPNValue for u32 {
     fn try_from_f64(value: f64) -> Status<Self> {
         Ok(value as u32)
     }
+
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
 }
 
 impl __sealed::Sealed for bool {}
@@ -82,6 +486,14 @@ impl SPNValue for bool {
     fn try_from_f64(value: f64) -> Status<Self> {
         Ok(value > 0.0)
     }
+
+    fn to_f64(self) -> f64 {
+        if self {
+            1.0
+        } else {
+            0.0
+        }
+    }
 }
 
 macro_rules! spn_for_unit {
@@ -91,6 +503,14 @@ macro_rules! spn_for_unit {
             fn try_from_f64(value: f64) -> Status<Self> {
                 Ok(frclib_core::units::$quan::$unit::from(value))
             }
+
+            fn to_f64(self) -> f64 {
+                self.value()
+            }
+
+            fn interpolate(self, other: Self, t: f64) -> Self {
+                Self::from(self.value() + (other.value() - self.value()) * t)
+            }
         }
     };
 }
@@ -103,3 +523,114 @@ spn_for_unit!(angular_acceleration::RotationPerSecSqr);
 spn_for_unit!(temperature::Celsius);
 spn_for_unit!(energy::Amp);
 spn_for_unit!(energy::Volt);
+
+#[cfg(test)]
+mod tests {
+    use frclib_core::units::{
+        angle::{Degree, Rotation},
+        angular_velocity::{DegreePerSec, RotationPerSec},
+    };
+
+    use crate::error::StatusCode;
+
+    use super::*;
+
+    fn sample<T: SPNValue>(value: T, can_timestamp: f64) -> SignalValue<T> {
+        SignalValue {
+            value,
+            can_timestamp,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn latency_compensate_mixes_units() {
+        // a Pigeon2 yaw in degrees with its angular velocity in degrees per second
+        let yaw = sample(Degree::new(90.0), 1.0);
+        let velocity = sample(DegreePerSec::new(180.0), 1.0);
+        let compensated = latency_compensate_to(yaw, velocity, Second::new(1.5));
+        assert!((compensated.value() - 180.0).abs() < 1e-9);
+
+        // a position in rotations with a velocity in degrees per second
+        let position = sample(Rotation::new(1.0), 2.0);
+        let velocity = sample(DegreePerSec::new(360.0), 2.0);
+        let compensated = latency_compensate_to(position, velocity, Second::new(2.25));
+        assert!((compensated.value() - 1.25).abs() < 1e-9);
+
+        // a position in degrees with a velocity in rotations per second
+        let position = sample(Degree::new(0.0), 2.0);
+        let velocity = sample(RotationPerSec::new(-1.0), 2.0);
+        let compensated = latency_compensate_to(position, velocity, Second::new(2.5));
+        assert!((compensated.value() + 180.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn from_raw_keeps_conversion_errors_in_status() {
+        let raw = SignalValueRaw {
+            value: 99.0,
+            software_timestamp: 1.0,
+            ..Default::default()
+        };
+        let value = SignalValue::<types::ForwardLimitValue>::from(raw);
+        assert!(value.status.is_err());
+        assert_eq!(value.value, types::ForwardLimitValue::default());
+        assert_eq!(value.software_timestamp, 1.0);
+
+        let value = SignalValue::<f64>::from(raw);
+        assert_eq!(value.status, Ok(()));
+        assert_eq!(value.value, 99.0);
+    }
+
+    #[test]
+    fn from_cached_reports_the_update_error_first() {
+        let cached = CachedValue {
+            raw: SignalValueRaw {
+                value: 99.0,
+                ..Default::default()
+            },
+            status: Err(StatusCode::SigNotUpdated),
+            invalidated: false,
+        };
+        let value = SignalValue::<types::ForwardLimitValue>::from(cached);
+        assert_eq!(value.status, Err(StatusCode::SigNotUpdated));
+    }
+
+    #[test]
+    fn invalidated_cache_fails_until_updated() {
+        let mut cached = CachedValue::default();
+        cached
+            .update(Ok(SignalValueRaw {
+                value: 1.0,
+                ..Default::default()
+            }))
+            .unwrap();
+        cached.invalidate();
+        assert_eq!(
+            cached.read::<f64>().map(|value| value.value),
+            Err(StatusCode::SigNotUpdated)
+        );
+
+        // a failed refresh does not make the old sample valid again
+        assert!(cached.update(Err(StatusCode::RxTimeout)).is_err());
+        assert_eq!(
+            cached.read::<f64>().map(|value| value.value),
+            Err(StatusCode::SigNotUpdated)
+        );
+
+        cached
+            .update(Ok(SignalValueRaw {
+                value: 2.0,
+                ..Default::default()
+            }))
+            .unwrap();
+        assert_eq!(cached.read::<f64>().map(|value| value.value), Ok(2.0));
+    }
+
+    #[test]
+    fn latency_compensate_without_a_sample() {
+        let position = sample(Degree::new(45.0), 0.0);
+        let velocity = sample(DegreePerSec::new(90.0), 0.0);
+        let compensated = latency_compensate_to(position, velocity, Second::new(3.0));
+        assert_eq!(compensated.value(), 45.0);
+    }
+}
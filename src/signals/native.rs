@@ -1,11 +1,13 @@
 #![allow(dead_code)]
-use std::os::raw::c_int;
+use std::{collections::HashMap, os::raw::c_int};
 
 use frclib_core::units::time::Time;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
 
 use crate::{devices::DeviceIdentifier, error::StatusCodeType, spn::SPN, Status};
 
-use super::{SPNValue, SignalValue};
+use super::{logger, SPNValue, SignalValue};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct SignalSpecifier {
@@ -27,6 +29,7 @@ impl SignalValueResponse {
             can_timestamp: self.can_timestamp,
             software_timestamp: self.software_timestamp,
             device_timestamp: self.device_timestamp,
+            status: Ok(()),
         })
     }
 }
@@ -90,6 +93,9 @@ pub fn request_signal_values<const N: usize>(
         resp.software_timestamp = sw_timestamps[i];
         resp.device_timestamp = can_timestamps[i];
     }
+    for (i, resp) in responses.iter().enumerate() {
+        record(hashes[i], spns[i], resp);
+    }
 
     Ok(responses)
 }
@@ -105,10 +111,11 @@ pub fn request_signal_values_dynamic(
         spns.push(req.spn as u32);
     }
 
-    let mut values = Vec::with_capacity(signals.len());
-    let mut can_timestamps = Vec::with_capacity(signals.len());
-    let mut software_timestamps = Vec::with_capacity(signals.len());
-    let mut device_timestamps = Vec::with_capacity(signals.len());
+    //these are written to by the ffi call so they need to be initialized to the full length
+    let mut values = vec![0f64; signals.len()];
+    let mut can_timestamps = vec![0f64; signals.len()];
+    let mut software_timestamps = vec![0f64; signals.len()];
+    let mut device_timestamps = vec![0f64; signals.len()];
     unsafe {
         ctre_phoenix6_sys::c_ctre_phoenix6_get_signal_simplified(
             meta.can_bus.as_ptr() as *const i8,
@@ -134,10 +141,73 @@ pub fn request_signal_values_dynamic(
             device_timestamp: device_timestamps[i],
         });
     }
+    for (i, resp) in responses.iter().enumerate() {
+        record(hashes[i], spns[i], resp);
+    }
 
     Ok(responses)
 }
 
+/// Requests the signals described by `hashes` and `spns` into caller provided buffers,
+/// this does not allocate so it can be used in hot loops
+#[allow(clippy::too_many_arguments)]
+pub fn request_signal_values_into(
+    can_bus: &str,
+    timeout: f64,
+    hashes: &[u32],
+    spns: &[u32],
+    values: &mut [f64],
+    can_timestamps: &mut [f64],
+    software_timestamps: &mut [f64],
+    device_timestamps: &mut [f64],
+) -> Status<()> {
+    let len = hashes.len();
+    if spns.len() != len
+        || values.len() != len
+        || can_timestamps.len() != len
+        || software_timestamps.len() != len
+        || device_timestamps.len() != len
+    {
+        return Err(crate::error::StatusCode::InvalidSize);
+    }
+    unsafe {
+        ctre_phoenix6_sys::c_ctre_phoenix6_get_signal_simplified(
+            can_bus.as_ptr() as *const i8,
+            c_int::from(timeout > 0.0),
+            timeout,
+            len as u32,
+            hashes.as_ptr(),
+            spns.as_ptr(),
+            values.as_mut_ptr(),
+            can_timestamps.as_mut_ptr(),
+            software_timestamps.as_mut_ptr(),
+            device_timestamps.as_mut_ptr(),
+        )
+        .to_result()?;
+    }
+    for i in 0..len {
+        logger::wpilog::record(
+            hashes[i],
+            spns[i],
+            values[i],
+            can_timestamps[i],
+            software_timestamps[i],
+        );
+    }
+    Ok(())
+}
+
+/// Hands a value read from a device to the wpilog signal recorder
+fn record(hash: u32, spn: u32, response: &SignalValueResponse) {
+    logger::wpilog::record(
+        hash,
+        spn,
+        response.value,
+        response.can_timestamp,
+        response.software_timestamp,
+    );
+}
+
 pub fn request_signal_value_single(
     meta: SignalMeta,
     signal: SignalSpecifier,
@@ -145,6 +215,11 @@ pub fn request_signal_value_single(
     request_signal_values(meta, [signal]).map(|v| v[0])
 }
 
+/// Every update frequency explicitly requested through this crate,
+/// used to restore them after the bus utilization of a device has been optimized
+static UPDATE_FREQUENCIES: Lazy<Mutex<HashMap<(String, SignalSpecifier), f64>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
 pub fn set_update_freq(meta: SignalMeta, signal: SignalSpecifier, freq_hz: f64) -> Status<()> {
     unsafe {
         ctre_phoenix6_sys::c_ctre_phoenix6_SetUpdateFrequency(
@@ -155,8 +230,12 @@ pub fn set_update_freq(meta: SignalMeta, signal: SignalSpecifier, freq_hz: f64)
             freq_hz,
             meta.timeout,
         )
-        .to_result()
+        .to_result()?;
     }
+    UPDATE_FREQUENCIES
+        .lock()
+        .insert((meta.can_bus, signal), freq_hz);
+    Ok(())
 }
 
 pub fn set_update_freq_all<T: Time>(
@@ -185,8 +264,13 @@ pub fn set_update_freq_all<T: Time>(
                 .map(|t| t.to_seconds().value())
                 .unwrap_or(crate::DEFAULT_TIMEOUT),
         )
-        .to_result()
+        .to_result()?;
     }
+    let mut frequencies = UPDATE_FREQUENCIES.lock();
+    for (network, signal) in signals {
+        frequencies.insert((network.clone(), *signal), freq_hz);
+    }
+    Ok(())
 }
 
 pub fn optimize_signals(meta: SignalMeta, device: DeviceIdentifier) -> Status<()> {
@@ -201,6 +285,49 @@ pub fn optimize_signals(meta: SignalMeta, device: DeviceIdentifier) -> Status<()
     }
 }
 
+/// Optimizes the bus utilization of the device while keeping every
+/// update frequency that was explicitly requested for it.
+///
+/// The explicit frequencies are restored with one request per frequency,
+/// every request is attempted and the first error is returned.
+pub fn optimize_signals_keep_explicit(meta: SignalMeta, device: DeviceIdentifier) -> Status<()> {
+    let hash = device.hash.0;
+    optimize_signals(meta.clone(), device)?;
+    let mut by_frequency: Vec<(f64, Vec<(String, SignalSpecifier)>)> = Vec::new();
+    for ((network, signal), freq_hz) in UPDATE_FREQUENCIES.lock().iter() {
+        if *network != meta.can_bus || signal.hash != hash {
+            continue;
+        }
+        let entry = (network.clone(), *signal);
+        match by_frequency
+            .iter_mut()
+            .find(|(other, _)| other.to_bits() == freq_hz.to_bits())
+        {
+            Some((_, signals)) => signals.push(entry),
+            None => by_frequency.push((*freq_hz, vec![entry])),
+        }
+    }
+    let mut result = Ok(());
+    for (freq_hz, signals) in by_frequency {
+        let restored = set_update_freq_all(
+            &signals,
+            freq_hz,
+            Some(frclib_core::units::time::Second::new(meta.timeout)),
+        );
+        if result.is_ok() {
+            result = restored;
+        }
+    }
+    result
+}
+
+/// Forgets every update frequency requested for the device
+pub fn forget_update_freqs(device: &DeviceIdentifier) {
+    UPDATE_FREQUENCIES.lock().retain(|(network, signal), _| {
+        !(*network == device.canbus && signal.hash == device.hash.0)
+    });
+}
+
 pub fn resend_freq_updates(meta: SignalMeta, device: DeviceIdentifier) -> Status<()> {
     unsafe {
         ctre_phoenix6_sys::c_ctre_phoenix6_ResendUpdateFrequencies(
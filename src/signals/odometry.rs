@@ -0,0 +1,311 @@
+use std::{
+    collections::VecDeque,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread::JoinHandle,
+    time::{Duration, Instant},
+};
+
+use parking_lot::Mutex;
+
+use crate::{error::StatusCode, Status};
+
+use super::{
+    group::{self, SignalHandle},
+    native::{self, SignalSpecifier},
+    BaseSignal, RefreshableStatusSignal, SPNValue, SignalValue, SignalValueRaw,
+};
+
+const DEFAULT_FREQUENCY_HZ: f64 = 250.0;
+const DEFAULT_CAPACITY: usize = 100;
+
+/// One synchronized read of every signal of an [`OdometryThread`]
+#[derive(Debug, Clone)]
+pub struct OdometrySample {
+    /// The mean of every signals [`SignalValue::timestamp`] in seconds
+    pub timestamp: f64,
+    owner: u64,
+    values: Vec<SignalValueRaw>,
+}
+impl OdometrySample {
+    /// The value of a signal added to the builder of the thread that took this sample
+    pub fn value<T: SPNValue>(&self, handle: SignalHandle<T>) -> Status<SignalValue<T>> {
+        handle.get(self.owner, &self.values)?.try_cast()
+    }
+}
+
+/// Timing statistics of an [`OdometryThread`]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct OdometryStats {
+    /// Samples taken per second over the last full second
+    pub achieved_rate_hz: f64,
+    pub samples: u64,
+    /// Reads that failed or arrived more than half a period late
+    pub missed_deadlines: u64,
+    /// Samples dropped because the buffer was full when they were taken
+    pub overwritten: u64,
+}
+
+struct Shared {
+    buffer: VecDeque<OdometrySample>,
+    capacity: usize,
+    stats: OdometryStats,
+}
+
+pub struct OdometryThreadBuilder {
+    owner: u64,
+    can_bus: Option<String>,
+    mixed_buses: bool,
+    signals: Vec<SignalSpecifier>,
+    frequency_hz: f64,
+    capacity: usize,
+}
+impl Default for OdometryThreadBuilder {
+    fn default() -> Self {
+        Self {
+            owner: group::next_owner(),
+            can_bus: None,
+            mixed_buses: false,
+            signals: Vec::new(),
+            frequency_hz: DEFAULT_FREQUENCY_HZ,
+            capacity: DEFAULT_CAPACITY,
+        }
+    }
+}
+impl OdometryThreadBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a signal to every sample, the returned handle reads its value from an [`OdometrySample`].
+    ///
+    /// Every signal must be on the same CAN bus so they can be waited on together,
+    /// [`start`](Self::start) fails with [`StatusCode::InvalidNetwork`] otherwise.
+    pub fn add<T, S>(&mut self, signal: &S) -> SignalHandle<T>
+    where
+        T: SPNValue,
+        S: BaseSignal<T> + RefreshableStatusSignal,
+    {
+        let identifier = signal.device_identifier();
+        match &self.can_bus {
+            Some(can_bus) => self.mixed_buses |= *can_bus != identifier.canbus,
+            None => self.can_bus = Some(identifier.canbus.clone()),
+        }
+        self.signals.push(SignalSpecifier {
+            hash: identifier.hash.0,
+            spn: signal.spn(),
+        });
+        SignalHandle::new(self.owner, self.signals.len() - 1)
+    }
+
+    /// The rate the signals are updated and sampled at, defaults to 250hz
+    pub fn frequency(mut self, frequency_hz: f64) -> Self {
+        self.frequency_hz = frequency_hz;
+        self
+    }
+
+    /// How many samples are kept before the oldest is overwritten, defaults to 100
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self
+    }
+
+    /// Sets the update frequency of every signal and starts sampling them
+    pub fn start(self) -> Status<OdometryThread> {
+        if !self.frequency_hz.is_finite() || self.frequency_hz <= 0.0 || self.capacity == 0 {
+            return Err(StatusCode::InvalidParamValue);
+        }
+        // the worker waits up to two periods, that has to fit in a duration too
+        let period = Duration::try_from_secs_f64(1.0 / self.frequency_hz)
+            .ok()
+            .filter(|period| !period.is_zero() && period.checked_mul(2).is_some())
+            .ok_or(StatusCode::InvalidParamValue)?;
+        if self.mixed_buses {
+            return Err(StatusCode::InvalidNetwork);
+        }
+        let can_bus = self.can_bus.ok_or(StatusCode::InvalidSize)?;
+        let update_list: Vec<(String, SignalSpecifier)> = self
+            .signals
+            .iter()
+            .map(|signal| (can_bus.clone(), *signal))
+            .collect();
+        native::set_update_freq_all::<frclib_core::units::time::Second>(
+            &update_list,
+            self.frequency_hz,
+            None,
+        )?;
+
+        let shared = Arc::new(Mutex::new(Shared {
+            buffer: VecDeque::with_capacity(self.capacity),
+            capacity: self.capacity,
+            stats: OdometryStats::default(),
+        }));
+        let running = Arc::new(AtomicBool::new(true));
+        let worker = OdometryWorker::new(
+            self.owner,
+            can_bus,
+            &self.signals,
+            period,
+            shared.clone(),
+            running.clone(),
+        );
+        let handle = std::thread::Builder::new()
+            .name("phoenix6-odometry".to_owned())
+            .spawn(move || worker.run())
+            .map_err(|_| StatusCode::GeneralError)?;
+        Ok(OdometryThread {
+            shared,
+            running,
+            handle: Some(handle),
+        })
+    }
+}
+
+/// A thread that waits on a set of signals at a high rate and buffers every
+/// synchronized sample, meant for odometry that needs more samples than the robot loop runs at.
+///
+/// The thread is stopped when this is dropped.
+pub struct OdometryThread {
+    shared: Arc<Mutex<Shared>>,
+    running: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+impl OdometryThread {
+    pub fn builder() -> OdometryThreadBuilder {
+        OdometryThreadBuilder::new()
+    }
+
+    /// Returns every sample taken since the last drain, oldest first
+    pub fn drain(&self) -> Vec<OdometrySample> {
+        self.shared.lock().buffer.drain(..).collect()
+    }
+
+    pub fn stats(&self) -> OdometryStats {
+        self.shared.lock().stats
+    }
+}
+impl Drop for OdometryThread {
+    fn drop(&mut self) {
+        self.running.store(false, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+struct OdometryWorker {
+    owner: u64,
+    can_bus: String,
+    hashes: Vec<u32>,
+    spns: Vec<u32>,
+    values: Vec<f64>,
+    can_timestamps: Vec<f64>,
+    software_timestamps: Vec<f64>,
+    device_timestamps: Vec<f64>,
+    period: Duration,
+    shared: Arc<Mutex<Shared>>,
+    running: Arc<AtomicBool>,
+}
+impl OdometryWorker {
+    fn new(
+        owner: u64,
+        can_bus: String,
+        signals: &[SignalSpecifier],
+        period: Duration,
+        shared: Arc<Mutex<Shared>>,
+        running: Arc<AtomicBool>,
+    ) -> Self {
+        let len = signals.len();
+        Self {
+            owner,
+            can_bus,
+            hashes: signals.iter().map(|signal| signal.hash).collect(),
+            spns: signals.iter().map(|signal| signal.spn as u32).collect(),
+            values: vec![0.0; len],
+            can_timestamps: vec![0.0; len],
+            software_timestamps: vec![0.0; len],
+            device_timestamps: vec![0.0; len],
+            period,
+            shared,
+            running,
+        }
+    }
+
+    fn run(mut self) {
+        // wait long enough for a late frame but short enough to notice being stopped
+        let timeout = (self.period * 2).as_secs_f64();
+        let late = self.period + self.period / 2;
+        let mut last_sample = Instant::now();
+        let mut window_start = Instant::now();
+        let mut window_samples = 0u32;
+
+        while self.running.load(Ordering::Relaxed) {
+            let started = Instant::now();
+            let result = native::request_signal_values_into(
+                &self.can_bus,
+                timeout,
+                &self.hashes,
+                &self.spns,
+                &mut self.values,
+                &mut self.can_timestamps,
+                &mut self.software_timestamps,
+                &mut self.device_timestamps,
+            );
+            let now = Instant::now();
+            let sample = result.ok().map(|_| self.sample());
+
+            let mut shared = self.shared.lock();
+            let missed = sample.is_none() || now.duration_since(last_sample) > late;
+            if missed {
+                shared.stats.missed_deadlines += 1;
+            }
+            if let Some(sample) = sample {
+                last_sample = now;
+                window_samples += 1;
+                shared.stats.samples += 1;
+                if shared.buffer.len() >= shared.capacity {
+                    shared.buffer.pop_front();
+                    shared.stats.overwritten += 1;
+                }
+                shared.buffer.push_back(sample);
+            }
+            let window = now.duration_since(window_start);
+            if window >= Duration::from_secs(1) {
+                shared.stats.achieved_rate_hz = f64::from(window_samples) / window.as_secs_f64();
+                window_start = now;
+                window_samples = 0;
+            }
+            drop(shared);
+
+            // a failing request can return immediately, do not spin on it
+            if result.is_err() {
+                if let Some(remaining) = self.period.checked_sub(started.elapsed()) {
+                    std::thread::sleep(remaining);
+                }
+            }
+        }
+    }
+
+    fn sample(&self) -> OdometrySample {
+        let values: Vec<SignalValueRaw> = (0..self.values.len())
+            .map(|i| SignalValueRaw {
+                value: self.values[i],
+                can_timestamp: self.can_timestamps[i],
+                software_timestamp: self.software_timestamps[i],
+                device_timestamp: self.device_timestamps[i],
+            })
+            .collect();
+        let timestamp = values
+            .iter()
+            .map(|raw| SignalValue::<f64>::from(*raw).timestamp())
+            .sum::<f64>()
+            / values.len() as f64;
+        OdometrySample {
+            timestamp,
+            owner: self.owner,
+            values,
+        }
+    }
+}
@@ -1,16 +1,16 @@
 use std::{
-    collections::{HashMap, VecDeque},
-    sync::{atomic::AtomicU32, Arc},
-    thread::Thread,
-    time::Duration,
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap},
+    sync::Arc,
+    thread::JoinHandle,
+    time::{Duration, Instant},
 };
 
-use flume::{Receiver, Sender};
-use frclib_core::time::Instant;
+use flume::{Receiver, RecvTimeoutError, Sender, TrySendError};
 use once_cell::sync::Lazy;
 use parking_lot::Mutex;
 
-use crate::devices::DeviceIdentifier;
+use crate::{devices::DeviceIdentifier, error::StatusCode, Status};
 
 use super::{native, SignalSource, SignalValueRaw};
 
@@ -21,10 +21,13 @@ static QUEUE_THREAD_MANAGER: Lazy<Mutex<QueueThreadManager>> = Lazy::new(|| {
     })
 });
 
+/// The most signals a single queue thread polls before another thread is spawned
+const SIGNALS_PER_THREAD: usize = 32;
+
 struct SignalCacheEntry {
-    count: u8,
+    count: u32,
     thread_index: usize,
-    receiver: Receiver<SignalValueRaw>,
+    delay: f64,
 }
 
 struct QueueThreadManager {
@@ -33,16 +36,66 @@ struct QueueThreadManager {
 }
 
 struct QueueThread {
-    thread: Thread,
+    handle: Option<JoinHandle<()>>,
     sender: Sender<QueueThreadMessage>,
-    signal_count: Arc<AtomicU32>,
+}
+
+/// The newest sample of a subscriber, kept after it is taken from the queue
+type LatestSample = Arc<Mutex<Option<SignalValueRaw>>>;
+
+/// The subscriber side of a polled signal, a bounded queue of samples
+pub(crate) struct SignalQueue {
+    pub(crate) receiver: Receiver<SignalValueRaw>,
+    pub(crate) latest: LatestSample,
+}
+
+/// The worker side of a [`SignalQueue`]
+#[derive(Debug)]
+struct QueueChannel {
+    sender: Sender<SignalValueRaw>,
+    /// Kept so the oldest sample can be dropped when the queue is full
+    receiver: Receiver<SignalValueRaw>,
+    latest: LatestSample,
+}
+impl QueueChannel {
+    /// Queues `value`, dropping the oldest samples while the queue is full.
+    /// Returns false once the subscriber is gone
+    fn push(&self, value: SignalValueRaw) -> bool {
+        if self.receiver.receiver_count() <= 1 {
+            return false;
+        }
+        *self.latest.lock() = Some(value);
+        let mut value = value;
+        loop {
+            match self.sender.try_send(value) {
+                Ok(()) => return true,
+                Err(TrySendError::Full(rejected)) => {
+                    value = rejected;
+                    let _ = self.receiver.try_recv();
+                }
+                Err(TrySendError::Disconnected(_)) => return false,
+            }
+        }
+    }
+}
+
+/// Creates both sides of a queue of up to `capacity` samples
+fn queue(capacity: usize) -> (QueueChannel, SignalQueue) {
+    let (sender, receiver) = flume::bounded(capacity);
+    let latest = LatestSample::default();
+    let channel = QueueChannel {
+        sender,
+        receiver: receiver.clone(),
+        latest: latest.clone(),
+    };
+    (channel, SignalQueue { receiver, latest })
 }
 
 #[derive(Debug)]
 enum QueueThreadMessage {
     NewSignal {
         source: SignalSource,
-        channel: Sender<SignalValueRaw>,
+        channel: QueueChannel,
         delay: f64,
     },
     DropSignal {
@@ -52,131 +105,370 @@ enum QueueThreadMessage {
         source: SignalSource,
         delay: f64,
     },
+    Shutdown,
 }
 
-impl QueueThread {
-    #[allow(clippy::comparison_chain)]
-    fn new() -> Self {
-        let (sender, receiver) = flume::unbounded();
-        let signal_count_out = Arc::new(AtomicU32::new(0));
-        let signal_count = signal_count_out.clone();
-        let thread = std::thread::spawn(move || {
-            let mut signals: HashMap<
-                SignalSource,
-                (Sender<SignalValueRaw>, f64, DeviceIdentifier),
-            > = HashMap::new();
-            let mut checks: VecDeque<(SignalSource, Instant)> = VecDeque::new();
-            loop {
-                if signals.is_empty() {
-                    std::thread::park();
-                }
-                if checks.len() > 1 {
-                    let poll = checks.pop_front().unwrap();
-                    let next = checks.front().unwrap();
-                    let wait_duration = next
-                        .1
-                        .checked_duration_since(Instant::now())
-                        .unwrap_or_else(|| Duration::from_secs(0));
-                    if let Ok(ret) = native::request_signal_value_single(
-                        native::SignalMeta {
-                            can_bus: signals[&poll.0].2.canbus.clone(),
-                            timeout: wait_duration.as_secs_f64(),
-                        },
-                        poll.0,
-                    ) {
-                        signals[&poll.0].0.send(ret).unwrap();
-                        checks.push_back((
-                            poll.0,
-                            Instant::now()
-                                .checked_add(Duration::from_secs_f64(signals[&poll.0].1))
-                                .unwrap(),
-                        ));
+/// A signal polled by a queue thread
+struct PolledSignal {
+    channels: Vec<QueueChannel>,
+    delay: Duration,
+    can_bus: String,
+    /// Set every time the signal is rescheduled outside of a poll,
+    /// deadlines with a different generation are stale and skipped
+    generation: u64,
+    /// The timestamp of the last sample sent, a poll that returns the same
+    /// sample again is not sent twice
+    last_timestamp: f64,
+}
+
+/// A deadline in the queue threads min-heap
+#[derive(Debug, Clone, Copy)]
+struct Deadline {
+    due: Instant,
+    source: SignalSource,
+    generation: u64,
+}
+impl PartialEq for Deadline {
+    fn eq(&self, other: &Self) -> bool {
+        self.due == other.due
+    }
+}
+impl Eq for Deadline {}
+impl PartialOrd for Deadline {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for Deadline {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // reversed so the binary heap pops the earliest deadline first
+        other.due.cmp(&self.due)
+    }
+}
+
+/// Converts a delay in seconds to a poll interval, `None` for delays that are
+/// not a positive finite number of seconds so they can never stall or spin the worker
+fn poll_delay(delay: f64) -> Option<Duration> {
+    Duration::try_from_secs_f64(delay)
+        .ok()
+        .filter(|delay| !delay.is_zero())
+}
+
+/// The state owned by a queue threads worker
+#[derive(Default)]
+struct QueueWorker {
+    signals: HashMap<SignalSource, PolledSignal>,
+    deadlines: BinaryHeap<Deadline>,
+    /// Shared by every signal so a signal that is dropped and added again
+    /// never matches a deadline left over from before
+    next_generation: u64,
+}
+impl QueueWorker {
+    fn run(mut self, receiver: Receiver<QueueThreadMessage>) {
+        loop {
+            // block until the next deadline or a message arrives
+            let message = match self.deadlines.peek() {
+                Some(next) => match receiver.recv_deadline(next.due) {
+                    Ok(message) => Some(message),
+                    Err(RecvTimeoutError::Timeout) => None,
+                    Err(RecvTimeoutError::Disconnected) => return,
+                },
+                None => match receiver.recv() {
+                    Ok(message) => Some(message),
+                    Err(_) => return,
+                },
+            };
+            match message {
+                Some(QueueThreadMessage::Shutdown) => return,
+                Some(message) => self.handle(message),
+                None => self.poll_due(),
+            }
+        }
+    }
+
+    fn handle(&mut self, message: QueueThreadMessage) {
+        match message {
+            QueueThreadMessage::NewSignal {
+                source,
+                channel,
+                delay,
+            } => {
+                // a bad delay drops the channel, the subscriber sees it as disconnected
+                let Some(delay) = poll_delay(delay) else {
+                    return;
+                };
+                if let Some(signal) = self.signals.get_mut(&source) {
+                    //another subscriber for a signal that is already polled
+                    signal.channels.push(channel);
+                    if delay < signal.delay {
+                        signal.delay = delay;
+                        self.reschedule(source);
                     }
-                } else if checks.len() == 1 {
-                    let poll = checks.pop_front().unwrap();
-                    if let Ok(ret) = native::request_signal_value_single(
-                        native::SignalMeta {
-                            can_bus: signals[&poll.0].2.canbus.clone(),
-                            timeout: Duration::from_secs(0).as_secs_f64(),
+                } else if let Some(id) = DeviceIdentifier::from_hash(source.hash) {
+                    self.signals.insert(
+                        source,
+                        PolledSignal {
+                            channels: vec![channel],
+                            delay,
+                            can_bus: id.canbus,
+                            generation: 0,
+                            last_timestamp: 0.0,
                         },
-                        poll.0,
-                    ) {
-                        signals[&poll.0].0.send(ret).unwrap();
-                        checks.push_back((
-                            poll.0,
-                            Instant::now()
-                                .checked_add(Duration::from_secs_f64(signals[&poll.0].1))
-                                .unwrap(),
-                        ));
-                    }
+                    );
+                    self.reschedule(source);
                 }
-                if !checks.is_empty() && receiver.is_empty() {
-                    continue;
+            }
+            QueueThreadMessage::DropSignal { source } => {
+                // its deadline is skipped when it is popped
+                self.signals.remove(&source);
+            }
+            QueueThreadMessage::UpdateDelay { source, delay } => {
+                let Some(delay) = poll_delay(delay) else {
+                    return;
+                };
+                if let Some(signal) = self.signals.get_mut(&source) {
+                    signal.delay = delay;
+                    self.reschedule(source);
                 }
-                match receiver.recv() {
-                    Ok(QueueThreadMessage::NewSignal {
-                        source,
-                        channel,
-                        delay,
-                    }) => {
-                        if let Some(id) = DeviceIdentifier::from_hash(source.hash) {
-                            signals.insert(source, (channel, delay, id));
-                            signal_count.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
-
-                            //add to checks
-                            let now = Instant::now();
-                            checks.push_back((
-                                source,
-                                now.checked_add(Duration::from_secs_f64(delay)).unwrap(),
-                            ));
-                        }
-                    }
-                    Ok(QueueThreadMessage::DropSignal { source }) => {
-                        if (signals.remove(&source)).is_some() {
-                            signal_count.fetch_sub(1, std::sync::atomic::Ordering::Relaxed);
+            }
+            QueueThreadMessage::Shutdown => {}
+        }
+    }
 
-                            //remove from checks
-                            checks.retain(|(s, _)| s != &source);
-                        }
-                    }
-                    Ok(QueueThreadMessage::UpdateDelay { source, delay }) => {
-                        if let Some((_, d, _)) = signals.get_mut(&source) {
-                            *d = delay;
-                        }
+    /// Invalidates the pending deadline of `source` and schedules one a full delay from now
+    fn reschedule(&mut self, source: SignalSource) {
+        if let Some(signal) = self.signals.get_mut(&source) {
+            self.next_generation += 1;
+            signal.generation = self.next_generation;
+            self.deadlines.push(Deadline {
+                due: Instant::now() + signal.delay,
+                source,
+                generation: signal.generation,
+            });
+        }
+    }
+
+    /// Polls every signal whose deadline has passed,
+    /// signals on the same bus are requested in a single call
+    fn poll_due(&mut self) {
+        let now = Instant::now();
+        let mut due: Vec<(String, Vec<Deadline>)> = Vec::new();
+        while let Some(next) = self.deadlines.peek().copied() {
+            if next.due > now {
+                break;
+            }
+            self.deadlines.pop();
+            let Some(signal) = self.signals.get(&next.source) else {
+                continue;
+            };
+            if signal.generation != next.generation {
+                continue;
+            }
+            match due.iter_mut().find(|(bus, _)| *bus == signal.can_bus) {
+                Some((_, deadlines)) => deadlines.push(next),
+                None => due.push((signal.can_bus.clone(), vec![next])),
+            }
+        }
+
+        for (can_bus, deadlines) in due {
+            let specifiers: Vec<SignalSource> = deadlines.iter().map(|d| d.source).collect();
+            let values = native::request_signal_values_dynamic(
+                native::SignalMeta {
+                    can_bus,
+                    timeout: 0.0,
+                },
+                &specifiers,
+            );
+            for (i, deadline) in deadlines.into_iter().enumerate() {
+                let Some(signal) = self.signals.get_mut(&deadline.source) else {
+                    continue;
+                };
+                if let Some(value) = values.as_ref().ok().and_then(|values| values.get(i)) {
+                    if value.software_timestamp != signal.last_timestamp {
+                        signal.last_timestamp = value.software_timestamp;
+                        signal.channels.retain(|channel| channel.push(*value));
                     }
-                    Err(_) => break,
                 }
+                // stay on the original cadence unless the worker fell a whole period behind
+                let mut next = deadline.due + signal.delay;
+                if next <= now {
+                    next = now + signal.delay;
+                }
+                self.deadlines.push(Deadline {
+                    due: next,
+                    ..deadline
+                });
             }
-        });
-        Self {
-            thread: thread.thread().clone(),
-            sender,
-            signal_count: signal_count_out,
         }
     }
+}
 
-    fn new_signal(&self, source: SignalSource, channel: Sender<SignalValueRaw>, delay: f64) {
+impl QueueThread {
+    fn new() -> Self {
+        let (sender, receiver) = flume::unbounded();
+        let handle = std::thread::Builder::new()
+            .name("phoenix6-queue".to_owned())
+            .spawn(move || QueueWorker::default().run(receiver))
+            .ok();
+        Self { handle, sender }
+    }
+
+    fn send(&self, message: QueueThreadMessage) -> Status<()> {
         self.sender
-            .send(QueueThreadMessage::NewSignal {
-                source,
-                channel,
-                delay,
+            .send(message)
+            .map_err(|_| StatusCode::GeneralError)
+    }
+
+    fn new_signal(&self, source: SignalSource, channel: QueueChannel, delay: f64) -> Status<()> {
+        self.send(QueueThreadMessage::NewSignal {
+            source,
+            channel,
+            delay,
+        })
+    }
+
+    fn drop_signal(&self, source: SignalSource) -> Status<()> {
+        self.send(QueueThreadMessage::DropSignal { source })
+    }
+
+    fn update_delay(&self, source: SignalSource, delay: f64) -> Status<()> {
+        self.send(QueueThreadMessage::UpdateDelay { source, delay })
+    }
+}
+impl Drop for QueueThread {
+    fn drop(&mut self) {
+        let _ = self.send(QueueThreadMessage::Shutdown);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl QueueThreadManager {
+    fn subscribe(
+        &mut self,
+        source: SignalSource,
+        delay: f64,
+        capacity: usize,
+    ) -> Status<SignalQueue> {
+        if capacity == 0 {
+            return Err(StatusCode::InvalidParamValue);
+        }
+        let (channel, queue) = queue(capacity);
+        if let Some(entry) = self.signal_cache.get_mut(&source) {
+            self.threads[entry.thread_index].new_signal(source, channel, delay)?;
+            entry.count += 1;
+            entry.delay = entry.delay.min(delay);
+            return Ok(queue);
+        }
+
+        let thread_index = (0..self.threads.len())
+            .map(|i| {
+                let load = self
+                    .signal_cache
+                    .values()
+                    .filter(|entry| entry.thread_index == i)
+                    .count();
+                (i, load)
             })
-            .unwrap();
-        if self.signal_count.load(std::sync::atomic::Ordering::Relaxed) == 0 {
-            self.thread.unpark();
+            .filter(|(_, load)| *load < SIGNALS_PER_THREAD)
+            .min_by_key(|(_, load)| *load)
+            .map(|(i, _)| i)
+            .unwrap_or_else(|| {
+                self.threads.push(QueueThread::new());
+                self.threads.len() - 1
+            });
+        self.threads[thread_index].new_signal(source, channel, delay)?;
+        self.signal_cache.insert(
+            source,
+            SignalCacheEntry {
+                count: 1,
+                thread_index,
+                delay,
+            },
+        );
+        Ok(queue)
+    }
+
+    fn unsubscribe(&mut self, source: SignalSource) {
+        let Some(entry) = self.signal_cache.get_mut(&source) else {
+            return;
+        };
+        entry.count -= 1;
+        if entry.count == 0 {
+            let thread_index = entry.thread_index;
+            self.signal_cache.remove(&source);
+            // if the worker is gone there is nothing left to stop polling
+            let _ = self.threads[thread_index].drop_signal(source);
         }
     }
 
-    fn drop_signal(&self, source: SignalSource) {
-        self.sender
-            .send(QueueThreadMessage::DropSignal { source })
-            .unwrap();
+    fn update_delay(&mut self, source: SignalSource, delay: f64) -> Status<()> {
+        match self.signal_cache.get_mut(&source) {
+            Some(entry) => {
+                entry.delay = delay;
+                self.threads[entry.thread_index].update_delay(source, delay)
+            }
+            None => Ok(()),
+        }
     }
+}
 
-    fn update_delay(&self, source: SignalSource, delay: f64) {
-        self.sender
-            .send(QueueThreadMessage::UpdateDelay { source, delay })
-            .unwrap();
+/// Starts polling `source` every `delay` seconds, every new sample is sent to the returned queue.
+/// The queue holds up to `capacity` samples, the oldest sample is dropped when it is full.
+///
+/// Multiple subscribers of the same signal share a single poll at the fastest requested rate.
+pub(crate) fn subscribe(source: SignalSource, delay: f64, capacity: usize) -> Status<SignalQueue> {
+    QUEUE_THREAD_MANAGER
+        .lock()
+        .subscribe(source, delay, capacity)
+}
+
+/// Removes a subscriber of `source`, polling stops when the last subscriber is gone
+pub(crate) fn unsubscribe(source: SignalSource) {
+    QUEUE_THREAD_MANAGER.lock().unsubscribe(source)
+}
+
+pub(crate) fn update_delay(source: SignalSource, delay: f64) -> Status<()> {
+    QUEUE_THREAD_MANAGER.lock().update_delay(source, delay)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(value: f64) -> SignalValueRaw {
+        SignalValueRaw {
+            value,
+            software_timestamp: value,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn full_queue_drops_the_oldest_sample() {
+        let (channel, queue) = queue(2);
+        for value in [1.0, 2.0, 3.0] {
+            assert!(channel.push(sample(value)));
+        }
+        let values: Vec<f64> = queue.receiver.try_iter().map(|raw| raw.value).collect();
+        assert_eq!(values, [2.0, 3.0]);
+    }
+
+    #[test]
+    fn latest_sample_outlives_the_queue() {
+        let (channel, queue) = queue(4);
+        assert!(queue.latest.lock().is_none());
+        channel.push(sample(1.0));
+        channel.push(sample(2.0));
+        assert_eq!(queue.receiver.try_iter().count(), 2);
+        assert_eq!(queue.latest.lock().map(|raw| raw.value), Some(2.0));
+    }
+
+    #[test]
+    fn push_stops_once_the_subscriber_is_gone() {
+        let (channel, queue) = queue(4);
+        drop(queue);
+        assert!(!channel.push(sample(1.0)));
     }
 }
@@ -0,0 +1,109 @@
+use flume::Receiver;
+use frclib_core::units::time::Time;
+
+use crate::{devices::DeviceIdentifier, error::StatusCode, spn::SPN, Status};
+
+use super::{
+    native, queue_thread, BaseSignal, QueuedStatusSignal, SPNValue, SignalSource, SignalValue,
+    SignalValueRaw,
+};
+
+/// A signal that is polled in the background, every sample received is queued
+/// until it is drained so no values are missed between robot loops.
+///
+/// The queue holds a fixed number of samples, when it is full the oldest sample
+/// is dropped so a signal that is never drained does not grow without bound.
+///
+/// Created with [`BaseSignal::queued`], the signal stops being polled once every
+/// queued signal for the same value is dropped.
+pub struct QueuedSignal<T: SPNValue> {
+    identifier: DeviceIdentifier,
+    source: SignalSource,
+    queue: queue_thread::SignalQueue,
+    phantom: std::marker::PhantomData<T>,
+}
+impl<T: SPNValue> QueuedSignal<T> {
+    pub(crate) fn new(hash: u32, spn: SPN, freq_hz: f64, capacity: usize) -> Status<Self> {
+        if !freq_hz.is_finite() || freq_hz <= 0.0 {
+            return Err(StatusCode::InvalidParamValue);
+        }
+        let identifier =
+            DeviceIdentifier::from_hash(hash).ok_or(StatusCode::InvalidDeviceDescriptor)?;
+        let source = SignalSource { hash, spn };
+        Ok(Self {
+            identifier,
+            source,
+            queue: queue_thread::subscribe(source, 1.0 / freq_hz, capacity)?,
+            phantom: std::marker::PhantomData,
+        })
+    }
+
+    pub(crate) fn receiver(&self) -> &Receiver<SignalValueRaw> {
+        &self.queue.receiver
+    }
+}
+
+impl<T: SPNValue> BaseSignal<T> for QueuedSignal<T> {
+    fn get_spn(&self) -> SPN {
+        self.source.spn
+    }
+
+    fn get_device_hash(&self) -> u32 {
+        self.source.hash
+    }
+
+    /// Sets how often the device sends the signal and how often it is polled
+    fn set_update_freq(&self, freq_hz: f64) -> Status<()> {
+        if !freq_hz.is_finite() || freq_hz <= 0.0 {
+            return Err(StatusCode::InvalidParamValue);
+        }
+        native::set_update_freq(
+            native::SignalMeta {
+                can_bus: self.identifier.canbus.clone(),
+                timeout: crate::DEFAULT_TIMEOUT,
+            },
+            self.source,
+            freq_hz,
+        )?;
+        queue_thread::update_delay(self.source, 1.0 / freq_hz)
+    }
+
+    /// Takes the oldest queued sample,
+    /// fails with [`StatusCode::SigNotUpdated`] if there is none
+    fn value(&self) -> Status<SignalValue<T>> {
+        self.queue
+            .receiver
+            .try_recv()
+            .map_err(|_| StatusCode::SigNotUpdated)?
+            .try_cast()
+    }
+
+    /// Checks the newest sample polled without taking anything from the queue,
+    /// a signal that has not been polled yet is stale
+    fn is_stale(&self, max_age: impl Time) -> Status<bool> {
+        match *self.queue.latest.lock() {
+            Some(raw) => Ok(raw.try_cast::<T>()?.is_stale(max_age)),
+            None => Ok(true),
+        }
+    }
+}
+
+impl<T: SPNValue> QueuedStatusSignal<T> for QueuedSignal<T> {
+    fn is_empty(&self) -> Status<bool> {
+        Ok(self.queue.receiver.is_empty())
+    }
+
+    fn drain(&self) -> Vec<SignalValue<T>> {
+        self.queue
+            .receiver
+            .try_iter()
+            .filter_map(|raw| raw.try_cast().ok())
+            .collect()
+    }
+}
+
+impl<T: SPNValue> Drop for QueuedSignal<T> {
+    fn drop(&mut self) {
+        queue_thread::unsubscribe(self.source);
+    }
+}
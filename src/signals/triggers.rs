@@ -0,0 +1,434 @@
+//! Callbacks that run when a signal changes or crosses a threshold.
+//!
+//! Every trigger is a [`QueuedSignal`] evaluated on a single background worker,
+//! callbacks run on that worker so they should return quickly.
+//! A callback that panics removes its own trigger, the other triggers keep running.
+
+use std::{
+    panic::{catch_unwind, AssertUnwindSafe},
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use flume::{RecvError, Selector, Sender};
+use frclib_core::units::time::Time;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+
+use crate::{error::StatusCode, spn::SPN, Status};
+
+use super::{
+    faults::{Fault, FaultSet},
+    queued::QueuedSignal,
+    BaseSignal, SPNValue, SignalValue, SignalValueRaw,
+};
+
+const DEFAULT_FREQUENCY_HZ: f64 = 50.0;
+/// Samples queued per trigger, the worker evaluates every sample as it arrives
+/// so only a stalled callback fills the queue
+const QUEUE_CAPACITY: usize = 64;
+
+/// The direction a value has to cross a threshold in for [`super::BaseSignal::on_threshold`] to fire
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Crossing {
+    Above,
+    Below,
+}
+
+/// How often a trigger is evaluated and how eagerly it fires
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TriggerOptions {
+    frequency_hz: f64,
+    debounce: f64,
+    hysteresis: f64,
+}
+impl Default for TriggerOptions {
+    fn default() -> Self {
+        Self {
+            frequency_hz: DEFAULT_FREQUENCY_HZ,
+            debounce: 0.0,
+            hysteresis: 0.0,
+        }
+    }
+}
+impl TriggerOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The rate the signal is polled at, defaults to 50hz
+    pub fn frequency(mut self, frequency_hz: f64) -> Self {
+        self.frequency_hz = frequency_hz;
+        self
+    }
+
+    /// How long a new value or crossed threshold has to hold before the callback runs,
+    /// defaults to firing immediately
+    pub fn debounce(mut self, debounce: impl Time) -> Self {
+        self.debounce = debounce.to_seconds().value();
+        self
+    }
+
+    /// How far a value has to come back past a threshold before the trigger can fire again,
+    /// only used by threshold triggers, defaults to zero
+    pub fn hysteresis(mut self, hysteresis: f64) -> Self {
+        self.hysteresis = hysteresis;
+        self
+    }
+}
+
+/// A registered trigger, the callback stops running when this is dropped
+#[must_use = "the trigger is removed when the subscription is dropped"]
+pub struct Subscription {
+    id: u64,
+}
+impl Subscription {
+    /// Removes the trigger, same as dropping the subscription
+    pub fn cancel(self) {}
+}
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        // if the worker is gone the trigger is already gone with it
+        if let Some(sender) = TRIGGER_WORKER.lock().as_ref() {
+            let _ = sender.send(TriggerMessage::Remove { id: self.id });
+        }
+    }
+}
+
+/// Holds a value until it has been stable for the debounce time
+struct Debouncer<V> {
+    debounce: f64,
+    pending: Option<(V, f64)>,
+}
+impl<V: PartialEq + Copy> Debouncer<V> {
+    fn new(debounce: f64) -> Self {
+        Self {
+            debounce,
+            pending: None,
+        }
+    }
+
+    /// Returns true once `value` has been seen continuously for the debounce time
+    fn settle(&mut self, value: V, timestamp: f64) -> bool {
+        match self.pending {
+            Some((pending, since)) if pending == value => timestamp - since >= self.debounce,
+            _ => {
+                self.pending = Some((value, timestamp));
+                self.debounce <= 0.0
+            }
+        }
+    }
+
+    fn reset(&mut self) {
+        self.pending = None;
+    }
+}
+
+/// Tracks whether a threshold trigger should fire, it fires once per crossing
+/// and re-arms when the value comes back past the threshold by the hysteresis
+struct ThresholdState {
+    crossing: Crossing,
+    threshold: f64,
+    hysteresis: f64,
+    armed: bool,
+    debouncer: Debouncer<bool>,
+}
+impl ThresholdState {
+    fn new(crossing: Crossing, threshold: f64, options: TriggerOptions) -> Self {
+        Self {
+            crossing,
+            threshold,
+            hysteresis: options.hysteresis,
+            armed: true,
+            debouncer: Debouncer::new(options.debounce),
+        }
+    }
+
+    /// Returns true if the trigger fires for `current`
+    fn update(&mut self, current: f64, timestamp: f64) -> bool {
+        let (crossed, rearm) = match self.crossing {
+            Crossing::Above => (
+                current > self.threshold,
+                current <= self.threshold - self.hysteresis,
+            ),
+            Crossing::Below => (
+                current < self.threshold,
+                current >= self.threshold + self.hysteresis,
+            ),
+        };
+        if !self.armed {
+            self.armed = rearm;
+            return false;
+        }
+        if !crossed {
+            self.debouncer.reset();
+            return false;
+        }
+        if self.debouncer.settle(true, timestamp) {
+            self.debouncer.reset();
+            self.armed = false;
+            return true;
+        }
+        false
+    }
+}
+
+type Evaluator = Box<dyn FnMut(SignalValueRaw) + Send>;
+
+struct Trigger {
+    signal: QueuedSignal<f64>,
+    evaluator: Evaluator,
+}
+
+enum TriggerMessage {
+    Add { id: u64, trigger: Trigger },
+    Remove { id: u64 },
+}
+
+enum TriggerEvent {
+    Message(Result<TriggerMessage, RecvError>),
+    Sample(u64, Result<SignalValueRaw, RecvError>),
+}
+
+static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+static TRIGGER_WORKER: Lazy<Mutex<Option<Sender<TriggerMessage>>>> = Lazy::new(|| Mutex::new(None));
+
+fn send(message: TriggerMessage) -> Status<()> {
+    let mut worker = TRIGGER_WORKER.lock();
+    let sender = match worker.as_ref() {
+        Some(sender) if !sender.is_disconnected() => sender,
+        _ => worker.insert(spawn_worker()?),
+    };
+    sender.send(message).map_err(|_| StatusCode::GeneralError)
+}
+
+fn spawn_worker() -> Status<Sender<TriggerMessage>> {
+    let (sender, receiver) = flume::unbounded();
+    std::thread::Builder::new()
+        .name("phoenix6-triggers".to_owned())
+        .spawn(move || {
+            let mut triggers: Vec<(u64, Trigger)> = Vec::new();
+            loop {
+                let event = triggers.iter().fold(
+                    Selector::new().recv(&receiver, TriggerEvent::Message),
+                    |selector, (id, trigger)| {
+                        let id = *id;
+                        selector.recv(trigger.signal.receiver(), move |sample| {
+                            TriggerEvent::Sample(id, sample)
+                        })
+                    },
+                );
+                match event.wait() {
+                    TriggerEvent::Message(Ok(TriggerMessage::Add { id, trigger })) => {
+                        triggers.push((id, trigger))
+                    }
+                    TriggerEvent::Message(Ok(TriggerMessage::Remove { id })) => {
+                        triggers.retain(|(other, _)| *other != id)
+                    }
+                    TriggerEvent::Message(Err(_)) => return,
+                    TriggerEvent::Sample(id, Ok(sample)) => {
+                        let Some((_, trigger)) =
+                            triggers.iter_mut().find(|(other, _)| *other == id)
+                        else {
+                            continue;
+                        };
+                        // a panicking callback only removes its own trigger,
+                        // every other trigger on the worker keeps running
+                        let evaluator = &mut trigger.evaluator;
+                        if catch_unwind(AssertUnwindSafe(|| evaluator(sample))).is_err() {
+                            triggers.retain(|(other, _)| *other != id);
+                        }
+                    }
+                    TriggerEvent::Sample(id, Err(_)) => triggers.retain(|(other, _)| *other != id),
+                }
+            }
+        })
+        .map_err(|_| StatusCode::GeneralError)?;
+    Ok(sender)
+}
+
+fn register(
+    hash: u32,
+    spn: SPN,
+    options: TriggerOptions,
+    evaluator: Evaluator,
+) -> Status<Subscription> {
+    let signal = QueuedSignal::new(hash, spn, options.frequency_hz, QUEUE_CAPACITY)?;
+    // the device has to send the signal at least as often as the trigger polls it,
+    // a signal turned off by `optimize_bus_utilization` would never fire otherwise
+    signal.set_update_freq(options.frequency_hz)?;
+    let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+    send(TriggerMessage::Add {
+        id,
+        trigger: Trigger { signal, evaluator },
+    })?;
+    Ok(Subscription { id })
+}
+
+pub(super) fn on_change<T: SPNValue + PartialEq + Send + 'static>(
+    hash: u32,
+    spn: SPN,
+    options: TriggerOptions,
+    mut callback: impl FnMut(SignalValue<T>) + Send + 'static,
+) -> Status<Subscription> {
+    let mut last: Option<T> = None;
+    let mut debouncer = Debouncer::new(options.debounce);
+    register(
+        hash,
+        spn,
+        options,
+        Box::new(move |raw| {
+            let Ok(value) = raw.try_cast::<T>() else {
+                return;
+            };
+            let Some(previous) = last else {
+                // the first sample is the baseline, not a change
+                last = Some(value.value);
+                return;
+            };
+            if value.value == previous {
+                debouncer.reset();
+                return;
+            }
+            if debouncer.settle(value.value, value.timestamp()) {
+                debouncer.reset();
+                last = Some(value.value);
+                callback(value);
+            }
+        }),
+    )
+}
+
+pub(super) fn on_threshold<T: SPNValue + Send + 'static>(
+    hash: u32,
+    spn: SPN,
+    crossing: Crossing,
+    threshold: f64,
+    options: TriggerOptions,
+    mut callback: impl FnMut(SignalValue<T>) + Send + 'static,
+) -> Status<Subscription> {
+    let mut state = ThresholdState::new(crossing, threshold, options);
+    register(
+        hash,
+        spn,
+        options,
+        Box::new(move |raw| {
+            let Ok(value) = raw.try_cast::<T>() else {
+                return;
+            };
+            if state.update(value.value.to_f64(), value.timestamp()) {
+                callback(value);
+            }
+        }),
+    )
+}
+
+pub(super) fn on_fault_raised<T: SPNValue + Into<FaultSet> + Send + 'static>(
+    hash: u32,
+    spn: SPN,
+    options: TriggerOptions,
+    mut callback: impl FnMut(Fault) + Send + 'static,
+) -> Status<Subscription> {
+    let mut active = FaultSet::default();
+    let mut debouncer = Debouncer::new(options.debounce);
+    register(
+        hash,
+        spn,
+        options,
+        Box::new(move |raw| {
+            let Ok(value) = raw.try_cast::<T>() else {
+                return;
+            };
+            let faults: FaultSet = value.value.into();
+            if faults == active {
+                debouncer.reset();
+                return;
+            }
+            if !debouncer.settle(faults, value.timestamp()) {
+                return;
+            }
+            debouncer.reset();
+            for fault in faults.iter().filter(|fault| !active.contains(*fault)) {
+                callback(fault);
+            }
+            active = faults;
+        }),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use frclib_core::units::time::Second;
+
+    use super::*;
+
+    #[test]
+    fn debouncer_without_debounce_settles_immediately() {
+        let mut debouncer = Debouncer::new(0.0);
+        assert!(debouncer.settle(1, 0.0));
+        assert!(debouncer.settle(2, 0.0));
+    }
+
+    #[test]
+    fn debouncer_waits_for_a_stable_value() {
+        let mut debouncer = Debouncer::new(0.25);
+        assert!(!debouncer.settle(1, 1.0));
+        assert!(!debouncer.settle(1, 1.125));
+        // a different value restarts the wait
+        assert!(!debouncer.settle(2, 1.25));
+        assert!(!debouncer.settle(2, 1.375));
+        assert!(debouncer.settle(2, 1.5));
+        debouncer.reset();
+        assert!(!debouncer.settle(2, 1.625));
+    }
+
+    fn threshold(crossing: Crossing, hysteresis: f64) -> ThresholdState {
+        ThresholdState::new(crossing, 10.0, TriggerOptions::new().hysteresis(hysteresis))
+    }
+
+    #[test]
+    fn threshold_fires_once_per_crossing() {
+        let mut state = threshold(Crossing::Above, 0.0);
+        assert!(!state.update(9.0, 0.0));
+        assert!(state.update(11.0, 0.1));
+        assert!(!state.update(12.0, 0.2));
+        // re-armed by going back to the threshold
+        assert!(!state.update(10.0, 0.3));
+        assert!(state.update(10.5, 0.4));
+    }
+
+    #[test]
+    fn threshold_rearms_past_the_hysteresis() {
+        let mut state = threshold(Crossing::Above, 2.0);
+        assert!(state.update(11.0, 0.0));
+        // back below the threshold but within the hysteresis
+        assert!(!state.update(9.0, 0.1));
+        assert!(!state.update(11.0, 0.2));
+        assert!(!state.update(8.0, 0.3));
+        assert!(state.update(11.0, 0.4));
+    }
+
+    #[test]
+    fn threshold_below_rearms_above_the_hysteresis() {
+        let mut state = threshold(Crossing::Below, 1.0);
+        assert!(state.update(9.0, 0.0));
+        assert!(!state.update(10.5, 0.1));
+        assert!(!state.update(9.0, 0.2));
+        assert!(!state.update(11.0, 0.3));
+        assert!(state.update(9.0, 0.4));
+    }
+
+    #[test]
+    fn threshold_debounces_the_crossing() {
+        let mut state = ThresholdState::new(
+            Crossing::Above,
+            10.0,
+            TriggerOptions::new().debounce(Second::new(0.25)),
+        );
+        assert!(!state.update(11.0, 1.0));
+        // dipping back resets the debounce
+        assert!(!state.update(9.0, 1.125));
+        assert!(!state.update(11.0, 1.25));
+        assert!(!state.update(11.0, 1.375));
+        assert!(state.update(11.0, 1.5));
+    }
+}
@@ -20,6 +20,15 @@ macro_rules! from_py {
                     fn try_from_f64(value: f64) -> crate::Status<Self> {
                         (value as u32).try_into().map_err(|_| crate::error::StatusCode::CouldNotCast)
                     }
+
+                    fn to_f64(self) -> f64 {
+                        self as u32 as f64
+                    }
+                }
+                impl crate::signals::logger::Loggable for [< $name:camel> ] {
+                    fn to_log_value(&self) -> crate::signals::logger::LogValue<'_> {
+                        crate::signals::logger::LogValue::Integer(*self as i64, "")
+                    }
                 }
                 impl Default for [< $name:camel> ] {
                     fn default() -> Self {